@@ -1,6 +1,9 @@
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, parse_quote, Data, DataEnum, DeriveInput, GenericParam, Ident, Type};
+use syn::{
+    parse::ParseStream, parse_macro_input, parse_quote, Data, DataEnum, DeriveInput, GenericParam,
+    Ident, Type,
+};
 
 enum Outcome<T> {
     #[allow(unused)]
@@ -14,6 +17,377 @@ struct Attributes {
     #[cfg(feature = "opt-in")]
     #[deluxe(default)]
     properties: Vec<syn::Path>,
+    #[deluxe(default)]
+    rename_all: Option<syn::LitStr>,
+}
+
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(inception))]
+struct VariantAttributes {
+    #[deluxe(default)]
+    rename: Option<syn::LitStr>,
+    #[deluxe(default)]
+    rename_all: Option<syn::LitStr>,
+}
+
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(inception))]
+struct FieldAttributes {
+    #[deluxe(default)]
+    skip: Skip,
+    #[deluxe(default)]
+    rename: Option<syn::LitStr>,
+    #[deluxe(default)]
+    meta: Annotations,
+}
+
+/// Case convention applied by `rename_all` to the names reported in metadata.
+#[derive(Clone, Copy)]
+enum RenameRule {
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+}
+
+impl RenameRule {
+    fn from_lit(lit: &Option<syn::LitStr>) -> Result<Option<Self>, TokenStream> {
+        let Some(lit) = lit else {
+            return Ok(None);
+        };
+        match lit.value().as_str() {
+            "camelCase" => Ok(Some(Self::Camel)),
+            "snake_case" => Ok(Some(Self::Snake)),
+            "SCREAMING_SNAKE_CASE" => Ok(Some(Self::ScreamingSnake)),
+            "kebab-case" => Ok(Some(Self::Kebab)),
+            _ => Err(syn::Error::new_spanned(
+                lit,
+                "Expected one of \"camelCase\", \"snake_case\", \"SCREAMING_SNAKE_CASE\" or \"kebab-case\".",
+            )
+            .into_compile_error()
+            .into()),
+        }
+    }
+
+    fn apply(self, name: &str) -> String {
+        let chars = name.chars().collect::<Vec<_>>();
+        let mut words: Vec<String> = vec![];
+        for (i, &c) in chars.iter().enumerate() {
+            if c == '_' {
+                words.push(String::new());
+                continue;
+            }
+            let prev = i.checked_sub(1).map(|i| chars[i]);
+            let next = chars.get(i + 1);
+            // A word starts at a capital after a lowercase letter or digit, and at the last
+            // capital of a run which is followed by lowercase, as in `HTTPServer`.
+            let starts_word = c.is_uppercase()
+                && prev.is_some_and(|p| {
+                    p.is_lowercase()
+                        || p.is_ascii_digit()
+                        || p.is_uppercase() && next.is_some_and(|n| n.is_lowercase())
+                });
+            if words.is_empty() || starts_word {
+                words.push(String::new());
+            }
+            if let Some(w) = words.last_mut() {
+                w.extend(c.to_lowercase());
+            }
+        }
+        let words = words.into_iter().filter(|w| !w.is_empty());
+
+        match self {
+            Self::Camel => words
+                .enumerate()
+                .map(|(i, w)| {
+                    if i == 0 {
+                        return w;
+                    }
+                    let mut chars = w.chars();
+                    chars
+                        .next()
+                        .map(|c| c.to_uppercase().chain(chars).collect())
+                        .unwrap_or_default()
+                })
+                .collect(),
+            Self::Snake => words.collect::<Vec<_>>().join("_"),
+            Self::ScreamingSnake => words.collect::<Vec<_>>().join("_").to_uppercase(),
+            Self::Kebab => words.collect::<Vec<_>>().join("-"),
+        }
+    }
+}
+
+/// The integer type named by a `#[repr(...)]` attribute, if any.
+fn integer_repr(attrs: &[syn::Attribute]) -> syn::Result<Option<Ident>> {
+    const INTEGERS: &[&str] = &[
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+    let mut repr = None;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if INTEGERS.iter().any(|i| ident == i) {
+                    repr = Some(ident.clone());
+                }
+            }
+            if meta.input.peek(syn::token::Paren) {
+                // e.g. `align(8)`
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+    Ok(repr)
+}
+
+/// The `///` comments among `attrs`, one line each with the leading space removed.
+fn docs(attrs: &[syn::Attribute]) -> String {
+    attrs
+        .iter()
+        .filter(|a| a.path().is_ident("doc"))
+        .filter_map(|a| match &a.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(s),
+                        ..
+                    }),
+                ..
+            }) => Some(s.value()),
+            _ => None,
+        })
+        .map(|line| line.strip_prefix(' ').map(str::to_owned).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `WriteTypeName` impl rendering the instantiated type and const arguments, if any. Each type
+/// argument writes its own name, so the impl requires it of every type parameter.
+fn write_type_name(name: &Ident, generics: &syn::Generics) -> proc_macro2::TokenStream {
+    let mut bounded = generics.clone();
+    let args = generics
+        .params
+        .iter()
+        .filter_map(|p| match p {
+            GenericParam::Type(t) => {
+                let t = &t.ident;
+                bounded
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote! { #t: ::inception::WriteTypeName });
+                Some(quote! { <#t as ::inception::WriteTypeName>::write_type_name(w)?; })
+            }
+            GenericParam::Const(c) => {
+                let c = &c.ident;
+                Some(quote! { write!(w, "{}", #c)?; })
+            }
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+    let (impl_generics, ty_generics, where_clause) = bounded.split_for_impl();
+    let args = (!args.is_empty()).then(|| {
+        let args = args.iter().enumerate().map(|(i, arg)| {
+            let sep = (i > 0).then(|| quote! { w.write_str(", ")?; });
+            quote! { #sep #arg }
+        });
+        quote! {
+            w.write_str("<")?;
+            #(#args)*
+            w.write_str(">")?;
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::inception::WriteTypeName for #name #ty_generics #where_clause {
+            fn write_type_name(w: &mut dyn ::core::fmt::Write) -> ::core::fmt::Result {
+                w.write_str(<Self as ::inception::DataType>::NAME)?;
+                #args
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Renders `ty` the way it is usually written, e.g. `Vec<&'a str>` rather than `Vec < & 'a str >`.
+fn type_name(ty: &Type) -> String {
+    let mut out = String::new();
+    for token in quote!(#ty).to_string().split(' ') {
+        let attach = out.is_empty()
+            || token.starts_with(['<', '>', ',', ')', ']', ':', ';'])
+            || out.ends_with(['<', '&', '(', '[', ':'])
+            || (token.starts_with(['(', '[']) && out.ends_with(|c: char| c.is_alphanumeric()));
+        if !attach {
+            out.push(' ');
+        }
+        out.push_str(token);
+    }
+    out
+}
+
+/// Type-level name of a field as an `inception::shape::Name`, `None` standing for an empty name.
+fn name_ty(variant: Option<&str>, field: Option<&str>) -> proc_macro2::TokenStream {
+    let chars = |name: Option<&str>| {
+        let chars = name
+            .unwrap_or_default()
+            .chars()
+            .map(proc_macro2::Literal::character);
+        quote! { (#(::inception::shape::Ch<#chars>,)*) }
+    };
+    let (variant, field) = (chars(variant), chars(field));
+    quote! { ::inception::shape::Name<#variant, #field> }
+}
+
+/// The name reported in metadata: an explicit `rename`, else `rename_all` applied to `ident`.
+fn external_name(ident: &Ident, rename: Option<syn::LitStr>, rule: Option<RenameRule>) -> String {
+    match (rename, rule) {
+        (Some(lit), _) => lit.value(),
+        (None, Some(rule)) => rule.apply(&ident.to_string()),
+        (None, None) => ident.to_string(),
+    }
+}
+
+#[derive(Default)]
+enum Skip {
+    #[default]
+    No,
+    Default,
+    With(syn::Expr),
+    Properties(Vec<syn::Path>, Option<syn::Expr>),
+}
+
+impl deluxe::ParseMetaItem for Skip {
+    fn parse_meta_item(input: ParseStream, _mode: deluxe::ParseMode) -> syn::Result<Self> {
+        input.parse().map(Self::With)
+    }
+
+    fn parse_meta_item_flag(_span: proc_macro2::Span) -> syn::Result<Self> {
+        Ok(Self::Default)
+    }
+
+    fn parse_meta_item_named(
+        input: ParseStream,
+        _name: &str,
+        span: proc_macro2::Span,
+    ) -> syn::Result<Self> {
+        if input.peek(syn::Token![=]) {
+            input.parse::<syn::Token![=]>()?;
+            Self::parse_meta_item(input, deluxe::ParseMode::Named(span))
+        } else if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let mut properties = vec![];
+            let mut value = None;
+            while !content.is_empty() {
+                if content.peek(Ident) && content.peek2(syn::Token![=]) {
+                    let key = content.parse::<Ident>()?;
+                    if key != "default" {
+                        return Err(syn::Error::new_spanned(key, "Expected `default = ...`"));
+                    }
+                    content.parse::<syn::Token![=]>()?;
+                    value = Some(content.parse()?);
+                } else {
+                    properties.push(content.parse()?);
+                }
+                if !content.is_empty() {
+                    content.parse::<syn::Token![,]>()?;
+                }
+            }
+            Ok(Self::Properties(properties, value))
+        } else {
+            Self::parse_meta_item_flag(span)
+        }
+    }
+}
+
+impl Skip {
+    fn value(&self) -> proc_macro2::TokenStream {
+        match self {
+            Self::With(expr) | Self::Properties(_, Some(expr)) => quote! { #expr },
+            _ => quote! { ::core::default::Default::default() },
+        }
+    }
+
+    fn properties(&self) -> Vec<syn::Path> {
+        match self {
+            Self::Properties(properties, _) => properties.clone(),
+            _ => vec![],
+        }
+    }
+}
+
+/// Key/value pairs from `#[inception(meta(unit = "ms", min = 0, required))]`.
+#[derive(Clone, Default)]
+struct Annotations(Vec<(Ident, Option<syn::Lit>, bool)>);
+
+impl deluxe::ParseMetaItem for Annotations {
+    fn parse_meta_item(input: ParseStream, _mode: deluxe::ParseMode) -> syn::Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+        let mut pairs = vec![];
+        while !content.is_empty() {
+            let key = content.parse::<Ident>()?;
+            let (value, negative) = if content.peek(syn::Token![=]) {
+                content.parse::<syn::Token![=]>()?;
+                let negative = content.parse::<Option<syn::Token![-]>>()?.is_some();
+                (Some(content.parse::<syn::Lit>()?), negative)
+            } else {
+                (None, false)
+            };
+            pairs.push((key, value, negative));
+            if !content.is_empty() {
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+        Ok(Self(pairs))
+    }
+
+    fn parse_meta_item_named(
+        input: ParseStream,
+        _name: &str,
+        span: proc_macro2::Span,
+    ) -> syn::Result<Self> {
+        Self::parse_meta_item(input, deluxe::ParseMode::Named(span))
+    }
+}
+
+impl Annotations {
+    fn tokens(&self) -> Result<proc_macro2::TokenStream, TokenStream> {
+        let pairs = self
+            .0
+            .iter()
+            .map(|(key, value, negative)| {
+                let sign = negative.then(|| quote! { - });
+                let value = match value {
+                    None => quote! { ::inception::Annotation::Bool(true) },
+                    Some(syn::Lit::Bool(b)) if !negative => {
+                        quote! { ::inception::Annotation::Bool(#b) }
+                    }
+                    Some(syn::Lit::Str(s)) if !negative => {
+                        quote! { ::inception::Annotation::Str(#s) }
+                    }
+                    Some(syn::Lit::Int(i)) => {
+                        let i = proc_macro2::Literal::i128_unsuffixed(i.base10_parse()?);
+                        quote! { ::inception::Annotation::Int(#sign #i) }
+                    }
+                    Some(syn::Lit::Float(f)) => {
+                        let f = proc_macro2::Literal::f64_unsuffixed(f.base10_parse()?);
+                        quote! { ::inception::Annotation::Float(#sign #f) }
+                    }
+                    Some(lit) => {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "Expected a string, integer, float or boolean annotation.",
+                        ))
+                    }
+                };
+                let key = key.to_string();
+                Ok(quote! { (#key, #value) })
+            })
+            .collect::<syn::Result<Vec<_>>>()
+            .map_err(|e| TokenStream::from(e.into_compile_error()))?;
+        Ok(quote! { &[#(#pairs),*] })
+    }
 }
 
 pub enum State {
@@ -26,22 +400,38 @@ impl State {
         let mut input: DeriveInput = parse_macro_input!(input);
 
         #[cfg(not(feature = "opt-in"))]
-        let Attributes { .. } = match deluxe::extract_attributes(&mut input) {
+        let Attributes { rename_all, .. } = match deluxe::extract_attributes(&mut input) {
             Ok(desc) => desc,
             Err(e) => return e.into_compile_error().into(),
         };
 
         #[cfg(feature = "opt-in")]
-        let Attributes { properties, .. } = match deluxe::extract_attributes(&mut input) {
+        let Attributes {
+            properties,
+            rename_all,
+            ..
+        } = match deluxe::extract_attributes(&mut input) {
             Ok(desc) => desc,
             Err(e) => return e.into_compile_error().into(),
         };
+        let rename_all = match RenameRule::from_lit(&rename_all) {
+            Ok(rule) => rule,
+            Err(e) => return e,
+        };
+
+        let repr = match integer_repr(&input.attrs) {
+            Ok(repr) => repr,
+            Err(e) => return e.into_compile_error().into(),
+        };
+
+        let write_type_name = write_type_name(&input.ident, &input.generics);
+        let docs = docs(&input.attrs);
 
         let mut transform_generics = input.generics.clone();
 
         let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-        let state = match State::try_from_data(&mut input.data, &input.ident) {
+        let state = match State::try_from_data(&mut input.data, &input.ident, rename_all) {
             Ok(Outcome::Process(st)) => st,
             Ok(Outcome::Skip) => {
                 return quote! {}.into();
@@ -51,46 +441,119 @@ impl State {
             }
         };
 
+        let (name, excluded) = match &state {
+            State::Struct(state) => (state.name.clone(), state.excluded_properties()),
+            State::Enum(state) => (state.name.clone(), state.excluded_properties()),
+        };
+
+        #[cfg(not(feature = "opt-in"))]
+        if let Some(p) = excluded.first() {
+            return syn::Error::new_spanned(p, "Per-property skips require the `opt-in` feature.")
+                .into_compile_error()
+                .into();
+        }
+
+        #[cfg(not(feature = "opt-in"))]
+        transform_generics.params.push(GenericParam::Type(
+            parse_quote! { X: ::inception::Property },
+        ));
+        #[cfg(feature = "opt-in")]
+        transform_generics.params.push(GenericParam::Type(
+            parse_quote! { X: ::inception::Property + ::inception::OptIn< #name #ty_generics > },
+        ));
+        let (transform_generics, _, _) = transform_generics.split_for_impl();
+
+        #[cfg(feature = "opt-in")]
+        if let Some(p) = excluded
+            .iter()
+            .find(|p| !properties.iter().any(|q| same_path(p, q)))
+        {
+            return syn::Error::new_spanned(
+                p,
+                "Skipped property is not listed in `#[inception(properties = [...])]`.",
+            )
+            .into_compile_error()
+            .into();
+        }
+
+        // Properties which exclude some field get their own impls instead of opting in to the
+        // blanket one, so that each sees a different set of fields.
+        #[cfg(feature = "opt-in")]
+        let properties = properties
+            .into_iter()
+            .filter(|p| !excluded.iter().any(|q| same_path(p, q)))
+            .collect::<Vec<_>>();
+        #[cfg(feature = "opt-in")]
+        let opts = quote! {
+            #(
+                impl #impl_generics ::inception::OptIn<#name #ty_generics> for #properties where #where_clause {}
+            )*
+        };
+        #[cfg(not(feature = "opt-in"))]
+        let opts = quote! {};
+
+        // Lookups by name are only needed to line this type up against others through `Shape`,
+        // which every type has without the `opt-in` feature.
+        #[cfg(feature = "opt-in")]
+        let shape = properties
+            .iter()
+            .any(|p| p.segments.last().is_some_and(|s| s.ident == "Shape"));
+        #[cfg(not(feature = "opt-in"))]
+        let shape = true;
+
+        let excluding = excluded.iter().map(|p| {
+            let (items, widths) = match &state {
+                State::Struct(state) => (state.excluding(p).items(), None),
+                State::Enum(state) => {
+                    let state = state.excluding(p);
+                    (state.items(), Some(state.widths()))
+                }
+            };
+            let widths = widths.map(|(widths, ty)| {
+                quote! {
+                    impl #impl_generics ::inception::VariantWidths<#p> for #name #ty_generics #where_clause {
+                        type Widths = #ty;
+                        const WIDTHS: Self::Widths = #widths;
+                    }
+                }
+            });
+            quote! {
+                impl #impl_generics ::inception::IsPrimitive<#p> for #name #ty_generics #where_clause {
+                    type Is = ::inception::False;
+                }
+                impl #impl_generics ::inception::Inception<#p, ::inception::False> for #name #ty_generics #where_clause {
+                    #items
+                }
+                #widths
+            }
+        })
+        .collect::<Vec<_>>();
+
         match state {
             State::Struct(state) => {
-                let field_names = state
-                    .field_identifiers
-                    .names()
-                    .into_iter()
-                    .map(|n| proc_macro2::Literal::string(n.to_string().as_str()));
-                let is_named = state.field_identifiers.is_named();
-                let ty_fields = state.field_tokens(Kind::Ty);
-                let ref_fields = state.field_tokens(Kind::Ref);
-                let mut_fields = state.field_tokens(Kind::Mut);
-                let owned_fields = state.field_tokens(Kind::Owned);
-                let fields_impl = state.field_impl(Kind::Ref);
-                let fields_mut_impl = state.field_impl(Kind::Mut);
-                let into_fields_impl = state.field_impl(Kind::Owned);
-                let from_fields_impl = state.impl_from_fields();
-                let StructState { name, .. } = state;
-
-                #[cfg(not(feature = "opt-in"))]
-                transform_generics.params.push(GenericParam::Type(
-                    parse_quote! { X: ::inception::Property },
-                ));
-                #[cfg(feature = "opt-in")]
-                transform_generics.params.push(GenericParam::Type(
-                    parse_quote! { X: ::inception::Property + ::inception::OptIn< #name #ty_generics > },
-                ));
-                let (transform_generics, _, _) = transform_generics.split_for_impl();
+                let field_names = state.fields.field_names();
+                let field_type_names = state.fields.field_type_names();
+                let field_docs = state.fields.field_docs();
+                let field_annotations = &state.fields.annotations;
+                let is_named = state.fields.is_named();
+                let items = state.items();
+                let by_name =
+                    shape.then(|| state.by_name(&impl_generics, &ty_generics, where_clause));
 
                 let num_fields =
-                    proc_macro2::Literal::usize_unsuffixed(state.field_identifiers.size());
+                    proc_macro2::Literal::usize_unsuffixed(state.fields.identifiers.size());
                 let fields_meta = if is_named {
                     quote! {
                         impl #impl_generics ::inception::NamedFieldsMeta for #name #ty_generics #where_clause {
                             const FIELD_NAMES: &'static [&'static str] = &[#(#field_names),*];
+                            const FIELD_DOCS: &'static [&'static str] = &[#(#field_docs),*];
                         }
                     }
                 } else {
                     quote! {
                         impl #impl_generics ::inception::UnnamedFieldsMeta for #name #ty_generics #where_clause {
                             const NUM_FIELDS: usize = #num_fields;
+                            const FIELD_DOCS: &'static [&'static str] = &[#(#field_docs),*];
                         }
                     }
                 };
@@ -101,101 +564,70 @@ impl State {
                     quote! { ::inception::False }
                 };
 
-                #[cfg(feature = "opt-in")]
-                let opts = quote! {
-                    #(
-                        impl #impl_generics ::inception::OptIn<#name #ty_generics> for #properties where #where_clause {}
-                    )*
-                };
-                #[cfg(not(feature = "opt-in"))]
-                let opts = quote! {};
-
                 quote! {
                     #opts
                     impl #impl_generics ::inception::DataType for #name #ty_generics #where_clause {
                         const NAME: &'static str = stringify!(#name);
+                        const DOCS: &'static str = #docs;
                         type Ty = ::inception::StructTy<#is_named>;
                     }
+                    #write_type_name
                     impl #impl_generics ::inception::StructMeta for #name #ty_generics #where_clause {
                             const NUM_FIELDS: usize = #num_fields;
+                            const FIELD_TYPE_NAMES: &'static [&'static str] = &[#(#field_type_names),*];
+                            const FIELD_ANNOTATIONS: &'static [&'static [(&'static str, ::inception::Annotation)]] = &[#(#field_annotations),*];
                             type NamedFields = #is_named;
                     }
                     #fields_meta
+                    #by_name
                     impl #transform_generics ::inception::IsPrimitive<X> for #name #ty_generics #where_clause {
                         type Is = ::inception::False;
                     }
                     impl #transform_generics ::inception::Inception<X, ::inception::False> for #name #ty_generics #where_clause {
-                        #ty_fields
-                        #ref_fields
-                        #mut_fields
-                        #owned_fields
-                        #fields_impl
-                        #fields_mut_impl
-                        #into_fields_impl
-                        #from_fields_impl
+                        #items
                     }
+                    #(#excluding)*
                 }
                 .into()
             }
 
             State::Enum(state) => {
-                let ty_fields = state.field_tokens(Kind::Ty);
-                let ref_fields = state.field_tokens(Kind::Ref);
-                let mut_fields = state.field_tokens(Kind::Mut);
-                let owned_fields = state.field_tokens(Kind::Owned);
-                let fields_impl = state.field_impl(Kind::Ref);
-                let fields_mut_impl = state.field_impl(Kind::Mut);
-                let into_fields_impl = state.field_impl(Kind::Owned);
-                let from_fields_impl = state.impl_from_fields();
-                let EnumState {
-                    name,
-                    variant_identifiers,
-                    ..
-                } = state;
-                let variant_names = variant_identifiers
+                let items = state.items();
+                let by_name =
+                    shape.then(|| state.by_name(&impl_generics, &ty_generics, where_clause));
+                let variant_names = state
+                    .variant_names
                     .iter()
-                    .map(|id| proc_macro2::Literal::string(id.to_string().as_str()))
+                    .map(|n| proc_macro2::Literal::string(n))
                     .collect::<Vec<_>>();
 
-                #[cfg(not(feature = "opt-in"))]
-                transform_generics.params.push(GenericParam::Type(
-                    parse_quote! { X: ::inception::Property },
-                ));
-                #[cfg(feature = "opt-in")]
-                transform_generics.params.push(GenericParam::Type(
-                    parse_quote! { X: ::inception::Property + ::inception::OptIn< #name #ty_generics > },
-                ));
-                let (transform_generics, _, _) = transform_generics.split_for_impl();
+                let discriminants = state.discriminants(repr.as_ref());
+                let repr = match &repr {
+                    Some(ty) => quote! { ::core::option::Option::Some(stringify!(#ty)) },
+                    None => quote! { ::core::option::Option::None },
+                };
 
                 let var_field_names = state
-                    .field_identifiers
+                    .fields
                     .iter()
-                    .map(|ids| {
-                        ids.names()
-                            .into_iter()
-                            .map(|n| proc_macro2::Literal::string(n.to_string().as_str()))
-                            .collect::<Vec<_>>()
-                    })
+                    .map(FieldSet::field_names)
                     .collect::<Vec<_>>();
-
-                let variant_parens = state
-                    .field_identifiers
+                let variant_docs = &state.variant_docs;
+                let var_field_annotations = state.fields.iter().map(|f| &f.annotations);
+                let var_field_docs = state
+                    .fields
                     .iter()
-                    .scan(0, |st, ids| {
-                        let res = (0..*st).map(|_| quote! { () });
-                        *st += ids.0.len() + 1;
-                        Some(res)
-                    })
+                    .map(FieldSet::field_docs)
                     .collect::<Vec<_>>();
-                let padding = variant_parens.into_iter().enumerate().map(|(i, parens)| {
-                    let parens = parens.collect::<Vec<_>>();
-                    let (pad, ty) = if parens.len() > 8 {
-                        (quote! { ::inception::list![#(#parens),*] }, quote! { ::inception::list_ty![#(#parens),*] })
-                    } else {
-                        let n = format_ident!("PAD_{}", parens.len());
-                        let m = format_ident!("Pad{}", parens.len());
-                        (quote! { ::inception::#n }, quote! { ::inception::#m })
-                    };
+                let var_field_type_names = state
+                    .fields
+                    .iter()
+                    .map(FieldSet::field_type_names)
+                    .collect::<Vec<_>>();
+
+                let (widths, widths_ty) = state.widths();
+                let padding = (0..state.fields.len()).map(|i| {
+                    let (pad, ty) = state.padding(i);
                     let n = proc_macro2::Literal::usize_unsuffixed(i);
                     quote! {
                         impl #impl_generics ::inception::VariantOffset<#n> for #name #ty_generics #where_clause {
@@ -205,39 +637,37 @@ impl State {
                     }
                 });
 
-                #[cfg(feature = "opt-in")]
-                let opts = quote! {
-                    #(
-                        impl #impl_generics ::inception::OptIn<#name #ty_generics> for #properties where #where_clause {}
-                    )*
-                };
-                #[cfg(not(feature = "opt-in"))]
-                let opts = quote! {};
-
                 quote! {
                     #opts
                     impl #impl_generics ::inception::DataType for #name #ty_generics #where_clause {
                         const NAME: &'static str = stringify!(#name);
+                        const DOCS: &'static str = #docs;
                         type Ty = ::inception::EnumTy;
                     }
+                    #write_type_name
                     impl #impl_generics ::inception::EnumMeta for #name #ty_generics #where_clause {
                         const VARIANT_NAMES: &'static [&'static str] = &[#(#variant_names),*];
                         const FIELD_NAMES: &'static [&'static [&'static str]] = &[#(&[#(#var_field_names),*]),*];
+                        const VARIANT_DOCS: &'static [&'static str] = &[#(#variant_docs),*];
+                        const FIELD_DOCS: &'static [&'static [&'static str]] = &[#(&[#(#var_field_docs),*]),*];
+                        const FIELD_ANNOTATIONS: &'static [&'static [&'static [(&'static str, ::inception::Annotation)]]] = &[#(&[#(#var_field_annotations),*]),*];
+                        const FIELD_TYPE_NAMES: &'static [&'static [&'static str]] = &[#(&[#(#var_field_type_names),*]),*];
+                        const DISCRIMINANTS: &'static [i128] = &[#(#discriminants),*];
+                        const REPR: ::core::option::Option<&'static str> = #repr;
                     }
                     #(#padding)*
+                    #by_name
                     impl #transform_generics ::inception::IsPrimitive<X> for #name #ty_generics #where_clause {
                         type Is = ::inception::False;
                     }
                     impl #transform_generics ::inception::Inception<X, ::inception::False> for #name #ty_generics #where_clause {
-                        #ty_fields
-                        #ref_fields
-                        #mut_fields
-                        #owned_fields
-                        #fields_impl
-                        #fields_mut_impl
-                        #into_fields_impl
-                        #from_fields_impl
+                        #items
+                    }
+                    impl #transform_generics ::inception::VariantWidths<X> for #name #ty_generics #where_clause {
+                        type Widths = #widths_ty;
+                        const WIDTHS: Self::Widths = #widths;
                     }
+                    #(#excluding)*
                 }
                 .into()
             }
@@ -249,8 +679,10 @@ pub struct EnumState {
     name: Ident,
     mod_label: Ident,
     variant_identifiers: Vec<Ident>,
-    field_identifiers: Vec<Identifiers>,
-    field_tys: Vec<Vec<Type>>,
+    variant_names: Vec<String>,
+    variant_docs: Vec<String>,
+    discriminants: Vec<Option<syn::Expr>>,
+    fields: Vec<FieldSet>,
 }
 
 enum Kind {
@@ -261,10 +693,215 @@ enum Kind {
 }
 
 impl EnumState {
+    fn items(&self) -> proc_macro2::TokenStream {
+        let ty_fields = self.field_tokens(Kind::Ty);
+        let ref_fields = self.field_tokens(Kind::Ref);
+        let mut_fields = self.field_tokens(Kind::Mut);
+        let owned_fields = self.field_tokens(Kind::Owned);
+        let fields_impl = self.field_impl(Kind::Ref);
+        let fields_mut_impl = self.field_impl(Kind::Mut);
+        let into_fields_impl = self.field_impl(Kind::Owned);
+        let try_from_fields_impl = self.impl_try_from_fields();
+        let variant_identifiers = &self.variant_identifiers;
+        let variant_idxs =
+            (0..variant_identifiers.len()).map(proc_macro2::Literal::usize_unsuffixed);
+        let variant_names = self
+            .variant_names
+            .iter()
+            .map(|n| proc_macro2::Literal::string(n));
+        quote! {
+            #ty_fields
+            #ref_fields
+            #mut_fields
+            #owned_fields
+            #fields_impl
+            #fields_mut_impl
+            #into_fields_impl
+            #try_from_fields_impl
+
+            fn variant_index(&self) -> usize {
+                match self {
+                    #(Self::#variant_identifiers { .. } => #variant_idxs,)*
+                }
+            }
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    #(Self::#variant_identifiers { .. } => #variant_names,)*
+                }
+            }
+        }
+    }
+
+    fn excluding(&self, property: &syn::Path) -> Self {
+        Self {
+            name: self.name.clone(),
+            mod_label: self.mod_label.clone(),
+            variant_identifiers: self.variant_identifiers.clone(),
+            variant_names: self.variant_names.clone(),
+            variant_docs: self.variant_docs.clone(),
+            discriminants: self.discriminants.clone(),
+            fields: self.fields.iter().map(|f| f.excluding(property)).collect(),
+        }
+    }
+
+    fn excluded_properties(&self) -> Vec<syn::Path> {
+        excluded_properties(self.fields.iter())
+    }
+
+    /// Discriminant of each variant as an `i128` expression. Implicit discriminants count up
+    /// from the last explicit one, or from zero.
+    fn discriminants(&self, repr: Option<&Ident>) -> Vec<proc_macro2::TokenStream> {
+        let repr = repr.map_or_else(|| quote! { isize }, |ty| quote! { #ty });
+        let mut base = quote! { 0 };
+        let mut offset = 0i128;
+        self.discriminants
+            .iter()
+            .map(|explicit| {
+                if let Some(expr) = explicit {
+                    base = quote! { ((#expr) as #repr as i128) };
+                    offset = 0;
+                }
+                let n = proc_macro2::Literal::i128_unsuffixed(offset);
+                offset += 1;
+                quote! { #base + #n }
+            })
+            .collect()
+    }
+
+    /// Lookups of each variant header and field by its type-level name.
+    fn by_name(
+        &self,
+        impl_generics: &syn::ImplGenerics,
+        ty_generics: &syn::TypeGenerics,
+        where_clause: Option<&syn::WhereClause>,
+    ) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        let variants = self
+            .fields
+            .iter()
+            .zip(&self.variant_identifiers)
+            .zip(&self.variant_names)
+            .enumerate()
+            .map(|(i, ((f, var), var_name))| {
+                let var_idx = proc_macro2::Literal::usize_unsuffixed(i);
+                let header_name = name_ty(Some(var_name), None);
+                let header = quote! {
+                    impl #impl_generics ::inception::shape::NameOf<#var_idx, { ::inception::field::HEADER_IDX }> for #name #ty_generics #where_clause {
+                        type Name = #header_name;
+                    }
+                    impl #impl_generics ::inception::shape::FieldByName<#header_name> for #name #ty_generics #where_clause {
+                        type Ty = ::inception::VariantHeader;
+                        type Ref<'__inception_ref> = ::inception::VarRefField<'__inception_ref, ::inception::VariantHeader, Self, #var_idx, { ::inception::field::HEADER_IDX }>
+                        where
+                            Self: '__inception_ref;
+
+                        fn field_by_name(&self) -> Self::Ref<'_> {
+                            match <Self as ::inception::shape::FieldByName<#header_name>>::get_by_name(self) {
+                                Some(header) => ::inception::VarRefField::header(header),
+                                None => ::inception::VarRefField::empty(),
+                            }
+                        }
+                        #[allow(unreachable_patterns)]
+                        fn get_by_name(&self) -> ::core::option::Option<&Self::Ty> {
+                            match self {
+                                Self::#var { .. } => Some(const { &::inception::VariantHeader::new(#var_idx) }),
+                                _ => None,
+                            }
+                        }
+                    }
+                };
+                let fields = f.identifiers.0.iter().zip(&f.names).zip(&f.tys).zip(&f.idxs).map(
+                    |(((id, field_name), ty), idx)| {
+                        let idx = proc_macro2::Literal::usize_unsuffixed(*idx);
+                        let field_name = name_ty(Some(var_name), Some(field_name));
+                        let member = id.member();
+                        quote! {
+                            impl #impl_generics ::inception::shape::NameOf<#var_idx, #idx> for #name #ty_generics #where_clause {
+                                type Name = #field_name;
+                            }
+                            impl #impl_generics ::inception::shape::FieldByName<#field_name> for #name #ty_generics #where_clause {
+                                type Ty = #ty;
+                                type Ref<'__inception_ref> = ::inception::VarRefField<'__inception_ref, #ty, Self, #var_idx, #idx>
+                                where
+                                    Self: '__inception_ref;
+
+                                fn field_by_name(&self) -> Self::Ref<'_> {
+                                    match <Self as ::inception::shape::FieldByName<#field_name>>::get_by_name(self) {
+                                        Some(field) => ::inception::VarRefField::new(field),
+                                        None => ::inception::VarRefField::empty(),
+                                    }
+                                }
+                                #[allow(unreachable_patterns)]
+                                fn get_by_name(&self) -> ::core::option::Option<&Self::Ty> {
+                                    match self {
+                                        Self::#var { #member: field, .. } => Some(field),
+                                        _ => None,
+                                    }
+                                }
+                            }
+                        }
+                    },
+                );
+                quote! {
+                    #header
+                    #(#fields)*
+                }
+            });
+        quote! { #(#variants)* }
+    }
+
+    /// Padding preceding the fields of the variant at `idx` in the flattened field list.
+    fn padding(&self, idx: usize) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let parens = self.fields[..idx]
+            .iter()
+            .flat_map(|f| std::iter::repeat_n(quote! { () }, f.identifiers.size() + 1))
+            .collect::<Vec<_>>();
+        if parens.len() > 8 {
+            (
+                quote! { ::inception::list![#(#parens),*] },
+                quote! { ::inception::list_ty![#(#parens),*] },
+            )
+        } else {
+            let n = format_ident!("PAD_{}", parens.len());
+            let m = format_ident!("Pad{}", parens.len());
+            (quote! { ::inception::#n }, quote! { ::inception::#m })
+        }
+    }
+
+    /// Length of each variant's run in the flattened field list, header included, as paddings.
+    fn widths(&self) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let (widths, tys): (Vec<_>, Vec<_>) = self
+            .fields
+            .iter()
+            .map(|f| {
+                let width = f.identifiers.size() + 1;
+                if width > 8 {
+                    let parens = std::iter::repeat_n(quote! { () }, width).collect::<Vec<_>>();
+                    (
+                        quote! { ::inception::list![#(#parens),*] },
+                        quote! { ::inception::list_ty![#(#parens),*] },
+                    )
+                } else {
+                    let n = format_ident!("PAD_{}", width);
+                    let m = format_ident!("Pad{}", width);
+                    (quote! { ::inception::#n }, quote! { ::inception::#m })
+                }
+            })
+            .unzip();
+        (
+            quote! { ::inception::list![#(#widths),*] },
+            quote! { ::inception::list_ty![#(#tys),*] },
+        )
+    }
+
     fn field_tokens(&self, kind: Kind) -> proc_macro2::TokenStream {
-        let fields = self.field_tys.iter().enumerate().map(|(i, tys)| {
+        let fields = self.fields.iter().enumerate().map(|(i, f)| {
             let var_idx = proc_macro2::Literal::usize_unsuffixed(i);
-            let ixs = (0..tys.len()).map(proc_macro2::Literal::usize_unsuffixed);
+            let ixs = f
+                .idxs
+                .iter()
+                .map(|ix| proc_macro2::Literal::usize_unsuffixed(*ix));
+            let tys = &f.tys;
             quote! {
                 [#var_idx, [#(#ixs, #tys),*]]
             }
@@ -291,109 +928,61 @@ impl EnumState {
     }
 
     fn field_impl(&self, kind: Kind) -> proc_macro2::TokenStream {
-        let variants = self
-            .field_tys
+        let expanded_variants = self
+            .fields
             .iter()
-            .zip(&self.field_identifiers)
             .zip(&self.variant_identifiers)
-            .map(|((tys, ids), var)| {
-                let mut named = false;
-                let fields = tys
-                    .iter()
-                    .zip(&ids.0)
-                    .map(|(_ty, id)| match id {
-                        Identifier::Unnamed(n) => {
-                            let n = format_ident!("_{n}");
-                            match kind {
-                                Kind::Ty => quote! {
-                                    VarTyField::new()
-                                },
-                                Kind::Ref => quote! {
-                                    VarRefField::new(#n)
-                                },
-                                Kind::Mut => quote! {
-                                    VarMutField::new(#n)
-                                },
-                                Kind::Owned => quote! {
-                                    VarOwnedField::new(#n)
-                                },
-                            }
-                        }
-
-                        Identifier::Named(n) => {
-                            named = true;
-                            match kind {
-                                Kind::Ty => quote! {
-                                    VarTyField::new()
-                                },
-                                Kind::Ref => quote! {
-                                    VarRefField::new(#n)
-                                },
-                                Kind::Mut => quote! {
-                                    VarMutField::new(#n)
-                                },
-                                Kind::Owned => quote! {
-                                    VarOwnedField::new(#n)
-                                },
-                            }
-                        }
-                    })
-                    .collect::<Vec<_>>();
-
-                let field_ids = ids
-                    .0
-                    .iter()
-                    .map(|id| match id {
-                        Identifier::Named(n) => n.clone(),
-                        Identifier::Unnamed(n) => format_ident!("_{n}"),
-                    })
-                    .collect::<Vec<_>>();
-
-                (var.clone(), field_ids, fields, named)
-            })
-            .collect::<Vec<_>>();
-
-        let expanded_variants = (0..variants.len())
-            .map(|i| {
-                let (var, field_ids, fields, named) = &variants[i];
-                let field_ids = field_ids.clone();
+            .enumerate()
+            .map(|(i, (f, var))| {
+                let fields = f.identifiers.0.iter().map(|id| {
+                    let n = id.binding();
+                    match kind {
+                        Kind::Ty => quote! {
+                            VarTyField::new()
+                        },
+                        Kind::Ref => quote! {
+                            VarRefField::new(#n)
+                        },
+                        Kind::Mut => quote! {
+                            VarMutField::new(#n)
+                        },
+                        Kind::Owned => quote! {
+                            VarOwnedField::new(#n)
+                        },
+                    }
+                });
 
+                let idx = proc_macro2::Literal::usize_unsuffixed(i);
                 let header = match kind {
                     Kind::Ty => {
                         quote! { VarTyField::header() }
                     }
                     Kind::Ref => {
-                        quote! { VarRefField::header(&inception::VariantHeader) }
+                        quote! { VarRefField::header(const { &::inception::VariantHeader::new(#idx) }) }
                     }
                     Kind::Mut => {
                         quote! { VarMutField::header(header) }
                     }
                     Kind::Owned => {
-                        quote! { VarOwnedField::header(::inception::VariantHeader) }
+                        quote! { VarOwnedField::header(::inception::VariantHeader::new(#idx)) }
                     }
                 };
-                let variant_fields = std::iter::once(header)
-                    .chain(fields.clone())
-                    .collect::<Vec<_>>();
+                let set_header = match kind {
+                    Kind::Mut => quote! { *header = ::inception::VariantHeader::new(#idx); },
+                    _ => quote! {},
+                };
+                let variant_fields = std::iter::once(header).chain(fields).collect::<Vec<_>>();
+                let pattern = f.destructure();
+                let (pad, _) = self.padding(i);
 
-                let i = proc_macro2::Literal::usize_unsuffixed(i);
-                let toks = if *named {
-                    quote! {
-                        Self::#var {
-                            #(#field_ids),*
-                        } => fields.mask(::inception::list![
-                            #(#variant_fields),*
-                        ].pad(<Self as ::inception::VariantOffset<#i>>::PADDING)),
-                    }
-                } else {
-                    quote! {
-                        Self::#var(#(#field_ids),*) => fields.mask(::inception::list![
+                quote! {
+                    Self::#var #pattern => {
+                        #set_header
+                        fields.mask(::inception::list![
                             #(#variant_fields),*
-                        ].pad(<Self as ::inception::VariantOffset<#i>>::PADDING)),
+                        ].pad(#pad))
                     }
-                };
-
-                toks
+                }
             })
             .collect::<Vec<_>>();
 
@@ -432,29 +1021,19 @@ impl EnumState {
         }
     }
 
-    fn impl_from_fields(&self) -> proc_macro2::TokenStream {
+    fn impl_try_from_fields(&self) -> proc_macro2::TokenStream {
         let (split, check): (Vec<_>, Vec<_>) = self
-            .field_tys
+            .fields
             .iter()
-            .zip(&self.field_identifiers)
             .zip(&self.variant_identifiers)
-            .enumerate()
-            .map(|(i, ((tys, ids), var))| {
-                let idx = proc_macro2::Literal::usize_unsuffixed(i);
-                let mut named = false;
-                let fields = tys
+            .map(|(f, var)| {
+                let fields = f
+                    .identifiers
+                    .0
                     .iter()
-                    .zip(&ids.0)
-                    .map(|(_ty, id)| match id {
-                        Identifier::Unnamed(n) => {
-                            let n = format_ident!("_{}", n);
-                            quote! { #n }
-                        }
-
-                        Identifier::Named(n) => {
-                            named = true;
-                            quote! { #n }
-                        }
+                    .map(|id| {
+                        let n = id.binding();
+                        quote! { #n }
                     })
                     .collect::<Vec<_>>();
 
@@ -465,7 +1044,6 @@ impl EnumState {
                     let split_parens = (0..fields.len() + 1).map(|_| quote! { () });
                     quote! { ::inception::list![#(#split_parens),*] }
                 };
-                quote! { <Self as ::inception::VariantOffset<#idx>>::PADDING };
                 let destruct_parens = fields
                     .iter()
                     .rev()
@@ -476,25 +1054,15 @@ impl EnumState {
                 let destructure = quote! {
                     let (header, #destruct_parens) = l.access().into_tuples();
                 };
+                let construct = f.construct(fields);
                 (
                     quote! {
                         #split
                     },
-                    if named {
-                        quote! {
-                            if l.0.0.has_value() {
-                                #destructure
-                                return Self :: #var {
-                                    #(#fields),*
-                                };
-                            }
-                        }
-                    } else {
-                        quote! {
-                            if l.0.0.has_value() {
-                                #destructure
-                                return Self :: #var(#(#fields),*);
-                            }
+                    quote! {
+                        if l.0.0.has_value() {
+                            #destructure
+                            return Ok(Self :: #var #construct);
                         }
                     },
                 )
@@ -502,13 +1070,15 @@ impl EnumState {
             .unzip();
 
         quote! {
-            fn from_fields(fields: Self::OwnedFields) -> Self {
+            fn try_from_fields(fields: Self::OwnedFields) -> ::core::result::Result<Self, ::inception::FromFieldsError> {
                 use ::inception::{SplitOff, Access, IntoTuples};
                 #(
                     #split
                     #check
                 )*
-                panic!("Failed to determine enum variant.");
+                Err(::inception::FromFieldsError::NoVariant {
+                    name: <Self as ::inception::DataType>::NAME,
+                })
             }
         }
     }
@@ -517,18 +1087,87 @@ impl EnumState {
 pub struct StructState {
     name: Ident,
     mod_label: Ident,
-    field_identifiers: Identifiers,
-    field_tys: Vec<Type>,
+    fields: FieldSet,
 }
 
 impl StructState {
+    fn items(&self) -> proc_macro2::TokenStream {
+        let ty_fields = self.field_tokens(Kind::Ty);
+        let ref_fields = self.field_tokens(Kind::Ref);
+        let mut_fields = self.field_tokens(Kind::Mut);
+        let owned_fields = self.field_tokens(Kind::Owned);
+        let fields_impl = self.field_impl(Kind::Ref);
+        let fields_mut_impl = self.field_impl(Kind::Mut);
+        let into_fields_impl = self.field_impl(Kind::Owned);
+        let try_from_fields_impl = self.impl_try_from_fields();
+        quote! {
+            #ty_fields
+            #ref_fields
+            #mut_fields
+            #owned_fields
+            #fields_impl
+            #fields_mut_impl
+            #into_fields_impl
+            #try_from_fields_impl
+        }
+    }
+
+    fn excluding(&self, property: &syn::Path) -> Self {
+        Self {
+            name: self.name.clone(),
+            mod_label: self.mod_label.clone(),
+            fields: self.fields.excluding(property),
+        }
+    }
+
+    fn excluded_properties(&self) -> Vec<syn::Path> {
+        excluded_properties(std::iter::once(&self.fields))
+    }
+
+    /// Lookups of each field by its type-level name.
+    fn by_name(
+        &self,
+        impl_generics: &syn::ImplGenerics,
+        ty_generics: &syn::TypeGenerics,
+        where_clause: Option<&syn::WhereClause>,
+    ) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        let f = &self.fields;
+        let fields = f.identifiers.0.iter().zip(&f.names).zip(&f.tys).zip(&f.idxs).map(
+            |(((id, field_name), ty), idx)| {
+                let idx = proc_macro2::Literal::usize_unsuffixed(*idx);
+                let field_name = name_ty(None, Some(field_name));
+                let member = id.member();
+                quote! {
+                    impl #impl_generics ::inception::shape::NameOf<0, #idx> for #name #ty_generics #where_clause {
+                        type Name = #field_name;
+                    }
+                    impl #impl_generics ::inception::shape::FieldByName<#field_name> for #name #ty_generics #where_clause {
+                        type Ty = #ty;
+                        type Ref<'__inception_ref> = ::inception::RefField<'__inception_ref, #ty, Self, #idx>
+                        where
+                            Self: '__inception_ref;
+
+                        fn field_by_name(&self) -> Self::Ref<'_> {
+                            ::inception::RefField::new(&self.#member)
+                        }
+                        fn get_by_name(&self) -> ::core::option::Option<&Self::Ty> {
+                            Some(&self.#member)
+                        }
+                    }
+                }
+            },
+        );
+        quote! { #(#fields)* }
+    }
+
     fn field_tokens(&self, kind: Kind) -> proc_macro2::TokenStream {
-        let (ixs, tys): (Vec<_>, Vec<_>) = self
-            .field_tys
+        let ixs = self
+            .fields
+            .idxs
             .iter()
-            .enumerate()
-            .map(|(i, x)| (proc_macro2::Literal::usize_unsuffixed(i), x))
-            .unzip();
+            .map(|ix| proc_macro2::Literal::usize_unsuffixed(*ix));
+        let tys = &self.fields.tys;
 
         match kind {
             Kind::Ty => quote! {
@@ -551,44 +1190,40 @@ impl StructState {
     }
 
     fn field_impl(&self, kind: Kind) -> proc_macro2::TokenStream {
-        let fields =
-            self.field_tys
-                .iter()
-                .zip(&self.field_identifiers.0)
-                .map(|(_ty, id)| match id {
-                    Identifier::Unnamed(n) => {
-                        let idx = proc_macro2::Literal::usize_unsuffixed(*n);
-                        match kind {
-                            Kind::Ty => quote! {
-                                ::inception::TyField::new()
-                            },
-                            Kind::Ref => quote! {
-                                ::inception::RefField::new(&self.#idx)
-                            },
-                            Kind::Mut => quote! {
-                                ::inception::MutField::new(&mut self.#idx)
-                            },
-                            Kind::Owned => quote! {
-                                ::inception::OwnedField::new(self.#idx)
-                            },
-                        }
-                    }
-
-                    Identifier::Named(n) => match kind {
-                        Kind::Ty => quote! {
-                            ::inception::TyField::new()
-                        },
-                        Kind::Ref => quote! {
-                            ::inception::RefField::new(&self.#n)
-                        },
-                        Kind::Mut => quote! {
-                            ::inception::MutField::new(&mut self.#n)
-                        },
-                        Kind::Owned => quote! {
-                            ::inception::OwnedField::new(self.#n)
-                        },
+        let fields = self.fields.identifiers.0.iter().map(|id| match id {
+            Identifier::Unnamed(n) => {
+                let idx = proc_macro2::Literal::usize_unsuffixed(*n);
+                match kind {
+                    Kind::Ty => quote! {
+                        ::inception::TyField::new()
                     },
-                });
+                    Kind::Ref => quote! {
+                        ::inception::RefField::new(&self.#idx)
+                    },
+                    Kind::Mut => quote! {
+                        ::inception::MutField::new(&mut self.#idx)
+                    },
+                    Kind::Owned => quote! {
+                        ::inception::OwnedField::new(self.#idx)
+                    },
+                }
+            }
+
+            Identifier::Named(n) => match kind {
+                Kind::Ty => quote! {
+                    ::inception::TyField::new()
+                },
+                Kind::Ref => quote! {
+                    ::inception::RefField::new(&self.#n)
+                },
+                Kind::Mut => quote! {
+                    ::inception::MutField::new(&mut self.#n)
+                },
+                Kind::Owned => quote! {
+                    ::inception::OwnedField::new(self.#n)
+                },
+            },
+        });
 
         match kind {
             Kind::Ty => quote! {
@@ -617,47 +1252,196 @@ impl StructState {
         }
     }
 
-    fn impl_from_fields(&self) -> proc_macro2::TokenStream {
-        let mut named = false;
-        let fields = self
-            .field_tys
-            .iter()
-            .zip(&self.field_identifiers.0)
-            .enumerate()
-            .map(|(depth, (_ty, id))| match id {
-                Identifier::Named(n) => {
-                    named = true;
-                    let path = (0..depth).map(|_| quote! { .0.1 });
-                    quote! { #n: fields #(#path)* .0.0.access() }
-                }
-                Identifier::Unnamed(_) => {
-                    let path = (0..depth).map(|_| quote! { .0.1 });
-                    quote! { fields #(#path)* .0.0.access() }
-                }
+    fn impl_try_from_fields(&self) -> proc_macro2::TokenStream {
+        let fields = (0..self.fields.tys.len())
+            .map(|depth| {
+                let path = (0..depth).map(|_| quote! { .0.1 });
+                quote! { fields #(#path)* .0.0.access() }
             })
             .collect::<Vec<_>>();
+        let construct = self.fields.construct(fields);
 
-        if named {
-            quote! {
-                fn from_fields(fields: Self::OwnedFields) -> Self {
-                    use ::inception::Access;
-                    Self {
-                        #(#fields),*
-                    }
-                }
+        quote! {
+            fn try_from_fields(fields: Self::OwnedFields) -> ::core::result::Result<Self, ::inception::FromFieldsError> {
+                use ::inception::Access;
+                Ok(Self #construct)
             }
+        }
+    }
+}
+
+/// The fields of a struct or enum variant, split by whether they take part in the traversal.
+#[derive(Clone, Default)]
+struct FieldSet {
+    identifiers: Identifiers,
+    names: Vec<String>,
+    docs: Vec<String>,
+    annotations: Vec<proc_macro2::TokenStream>,
+    tys: Vec<Type>,
+    idxs: Vec<usize>,
+    excludes: Vec<Vec<syn::Path>>,
+    exclude_values: Vec<proc_macro2::TokenStream>,
+    skipped: Vec<Skipped>,
+}
+
+#[derive(Clone)]
+struct Skipped {
+    id: Identifier,
+    value: proc_macro2::TokenStream,
+}
+
+impl FieldSet {
+    fn is_named(&self) -> bool {
+        self.identifiers.is_named()
+            || matches!(
+                self.skipped.first(),
+                Some(Skipped {
+                    id: Identifier::Named(_),
+                    ..
+                })
+            )
+    }
+
+    /// Moves every field excluded from `property` into the skipped set.
+    fn excluding(&self, property: &syn::Path) -> Self {
+        let mut set = Self {
+            skipped: self.skipped.clone(),
+            ..Default::default()
+        };
+        for (((((((id, name), docs), annotations), ty), idx), excludes), value) in self
+            .identifiers
+            .0
+            .iter()
+            .zip(&self.names)
+            .zip(&self.docs)
+            .zip(&self.annotations)
+            .zip(&self.tys)
+            .zip(&self.idxs)
+            .zip(&self.excludes)
+            .zip(&self.exclude_values)
+        {
+            if excludes.iter().any(|p| same_path(p, property)) {
+                set.skipped.push(Skipped {
+                    id: id.clone(),
+                    value: value.clone(),
+                });
+            } else {
+                set.identifiers.0.push(id.clone());
+                set.names.push(name.clone());
+                set.docs.push(docs.clone());
+                set.annotations.push(annotations.clone());
+                set.tys.push(ty.clone());
+                set.idxs.push(*idx);
+                set.excludes.push(excludes.clone());
+                set.exclude_values.push(value.clone());
+            }
+        }
+        set
+    }
+
+    /// Doc comments of the traversed fields.
+    fn field_docs(&self) -> Vec<&str> {
+        self.docs.iter().map(String::as_str).collect()
+    }
+
+    /// Metadata names of the traversed named fields.
+    fn field_names(&self) -> Vec<proc_macro2::Literal> {
+        self.identifiers
+            .0
+            .iter()
+            .zip(&self.names)
+            .filter(|(id, _)| matches!(id, Identifier::Named(_)))
+            .map(|(_, name)| proc_macro2::Literal::string(name))
+            .collect()
+    }
+
+    /// Stringified types of the traversed fields.
+    fn field_type_names(&self) -> Vec<proc_macro2::TokenStream> {
+        self.tys
+            .iter()
+            .map(|ty| {
+                let name = type_name(ty);
+                quote! { #name }
+            })
+            .collect()
+    }
+
+    /// Pattern binding every traversed field, ignoring skipped ones.
+    fn destructure(&self) -> proc_macro2::TokenStream {
+        if self.identifiers.size() + self.skipped.len() == 0 {
+            quote! { {} }
+        } else if self.is_named() {
+            let names = self.identifiers.names();
+            let rest = (!self.skipped.is_empty()).then(|| quote! { .. });
+            quote! { { #(#names,)* #rest } }
         } else {
-            quote! {
-                fn from_fields(fields: Self::OwnedFields) -> Self {
-                    use ::inception::Access;
-                    Self(#(#fields),*)
+            let bindings = (0..self.identifiers.size() + self.skipped.len()).map(|i| {
+                if self
+                    .identifiers
+                    .0
+                    .iter()
+                    .any(|id| matches!(id, Identifier::Unnamed(n) if *n == i))
+                {
+                    let n = format_ident!("_{i}");
+                    quote! { #n }
+                } else {
+                    quote! { _ }
                 }
-            }
+            });
+            quote! { (#(#bindings),*) }
+        }
+    }
+
+    /// Constructor body from the traversed field values, filling skipped fields with their defaults.
+    fn construct(&self, values: Vec<proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
+        let mut fields = self
+            .identifiers
+            .0
+            .iter()
+            .zip(values)
+            .chain(self.skipped.iter().map(|s| (&s.id, s.value.clone())))
+            .collect::<Vec<_>>();
+
+        if fields.is_empty() {
+            quote! { {} }
+        } else if self.is_named() {
+            let fields = fields.into_iter().map(|(id, value)| match id {
+                Identifier::Named(n) => quote! { #n: #value },
+                Identifier::Unnamed(_) => quote! { #value },
+            });
+            quote! { { #(#fields),* } }
+        } else {
+            fields.sort_by_key(|(id, _)| match id {
+                Identifier::Unnamed(n) => *n,
+                Identifier::Named(_) => 0,
+            });
+            let fields = fields.into_iter().map(|(_, value)| value);
+            quote! { (#(#fields),*) }
         }
     }
 }
 
-#[derive(Default)]
+/// Properties are told apart by the last segment of their path, so that `crate::eq::SameSame`
+/// in a `skip(...)` names the same property as `SameSame` in `properties = [...]`.
+fn same_path(a: &syn::Path, b: &syn::Path) -> bool {
+    match (a.segments.last(), b.segments.last()) {
+        (Some(a), Some(b)) => a.ident == b.ident,
+        _ => false,
+    }
+}
+
+/// Every property named in a field-level `#[inception(skip(...))]`, without duplicates.
+fn excluded_properties<'a>(sets: impl Iterator<Item = &'a FieldSet>) -> Vec<syn::Path> {
+    let mut properties: Vec<syn::Path> = vec![];
+    for p in sets.flat_map(|f| f.excludes.iter().flatten()) {
+        if !properties.iter().any(|q| same_path(p, q)) {
+            properties.push(p.clone());
+        }
+    }
+    properties
+}
+
+#[derive(Clone, Default)]
 struct Identifiers(Vec<Identifier>);
 impl Identifiers {
     fn names(&self) -> Vec<Ident> {
@@ -682,12 +1466,28 @@ impl Identifiers {
     }
 }
 
+#[derive(Clone)]
 pub enum Identifier {
     Named(Ident),
     Unnamed(usize),
 }
 
 impl Identifier {
+    /// The field as named in a field access or struct pattern, e.g. `self.name` or `Self::V { 0: .. }`.
+    fn member(&self) -> syn::Member {
+        match self {
+            Identifier::Named(n) => syn::Member::Named(n.clone()),
+            Identifier::Unnamed(n) => syn::Member::Unnamed((*n).into()),
+        }
+    }
+
+    fn binding(&self) -> Ident {
+        match self {
+            Identifier::Named(n) => n.clone(),
+            Identifier::Unnamed(n) => format_ident!("_{n}"),
+        }
+    }
+
     pub fn modularize(ident: &Ident) -> Ident {
         format_ident!(
             "{}",
@@ -712,8 +1512,7 @@ impl State {
         Self::Struct(StructState {
             name: ident.clone(),
             mod_label: format_ident!("inception_struct_{}", Identifier::modularize(ident)),
-            field_identifiers: Default::default(),
-            field_tys: Default::default(),
+            fields: Default::default(),
         })
     }
 
@@ -722,19 +1521,27 @@ impl State {
             name: ident.clone(),
             mod_label: format_ident!("inception_enum_{}", Identifier::modularize(ident)),
             variant_identifiers: Default::default(),
-            field_identifiers: Default::default(),
-            field_tys: Default::default(),
+            variant_names: Default::default(),
+            variant_docs: Default::default(),
+            discriminants: Default::default(),
+            fields: Default::default(),
         })
     }
 
-    fn try_from_data(data: &mut syn::Data, ident: &Ident) -> Result<Outcome<Self>, TokenStream> {
+    fn try_from_data(
+        data: &mut syn::Data,
+        ident: &Ident,
+        rename_all: Option<RenameRule>,
+    ) -> Result<Outcome<Self>, TokenStream> {
         match data {
             Data::Enum(DataEnum { variants, .. }) => {
                 let State::Enum(EnumState {
                     name,
                     mut variant_identifiers,
-                    mut field_identifiers,
-                    mut field_tys,
+                    mut variant_names,
+                    mut variant_docs,
+                    mut discriminants,
+                    mut fields,
                     mod_label,
                 }) = State::new_enum(ident)
                 else {
@@ -744,41 +1551,36 @@ impl State {
                 };
 
                 for v in variants {
+                    discriminants.push(v.discriminant.as_ref().map(|(_, e)| e.clone()));
+
+                    let VariantAttributes {
+                        rename,
+                        rename_all: rename_fields,
+                    } = match deluxe::extract_attributes(v) {
+                        Ok(attrs) => attrs,
+                        Err(e) => return Err(e.into_compile_error().into()),
+                    };
+                    let rename_fields = RenameRule::from_lit(&rename_fields)?;
+                    variant_names.push(external_name(&v.ident, rename, rename_all));
+                    variant_docs.push(docs(&v.attrs));
                     variant_identifiers.push(v.ident.clone());
-                    let (ids, tys): (Vec<_>, Vec<_>) = v
-                        .fields
-                        .iter()
-                        .enumerate()
-                        .map(|(i, f)| {
-                            (
-                                f.ident
-                                    .clone()
-                                    .map(Identifier::Named)
-                                    .unwrap_or(Identifier::Unnamed(i)),
-                                f.ty.clone(),
-                            )
-                        })
-                        .unzip();
-
-                    field_identifiers.push(Identifiers(ids));
-                    field_tys.push(tys);
+                    fields.push(Self::partition_fields(&mut v.fields, rename_fields)?);
                 }
 
                 Ok(Outcome::Process(State::Enum(EnumState {
                     name,
                     mod_label,
                     variant_identifiers,
-                    field_identifiers,
-                    field_tys,
+                    variant_names,
+                    variant_docs,
+                    discriminants,
+                    fields,
                 })))
             }
 
             Data::Struct(x) => {
                 let State::Struct(StructState {
-                    mut field_tys,
-                    mod_label,
-                    name,
-                    ..
+                    mod_label, name, ..
                 }) = State::new_struct(ident)
                 else {
                     return Err(syn::Error::new_spanned(&x.fields, "Expected struct.")
@@ -786,27 +1588,10 @@ impl State {
                         .into());
                 };
 
-                let (ids, tys): (Vec<_>, Vec<_>) = x
-                    .fields
-                    .iter()
-                    .enumerate()
-                    .map(|(i, f)| {
-                        (
-                            f.ident
-                                .clone()
-                                .map(Identifier::Named)
-                                .unwrap_or(Identifier::Unnamed(i)),
-                            f.ty.clone(),
-                        )
-                    })
-                    .unzip();
-
-                let field_identifiers = Identifiers(ids);
-                field_tys.extend(tys);
+                let fields = Self::partition_fields(&mut x.fields, rename_all)?;
 
                 Ok(Outcome::Process(State::Struct(StructState {
-                    field_identifiers,
-                    field_tys,
+                    fields,
                     mod_label,
                     name,
                 })))
@@ -819,4 +1604,52 @@ impl State {
             ),
         }
     }
+
+    /// Splits fields into those included in the traversal and those marked `#[inception(skip)]`.
+    fn partition_fields(
+        fields: &mut syn::Fields,
+        rename_all: Option<RenameRule>,
+    ) -> Result<FieldSet, TokenStream> {
+        let mut set = FieldSet::default();
+        for (i, f) in fields.iter_mut().enumerate() {
+            let FieldAttributes { skip, rename, meta } = match deluxe::extract_attributes(f) {
+                Ok(attrs) => attrs,
+                Err(e) => return Err(e.into_compile_error().into()),
+            };
+            let id = f
+                .ident
+                .clone()
+                .map(Identifier::Named)
+                .unwrap_or(Identifier::Unnamed(i));
+            if let (None, Some(rename)) = (&f.ident, &rename) {
+                return Err(syn::Error::new_spanned(
+                    rename,
+                    "`rename` only applies to named fields.",
+                )
+                .into_compile_error()
+                .into());
+            }
+            match skip {
+                Skip::Default | Skip::With(_) => set.skipped.push(Skipped {
+                    id,
+                    value: skip.value(),
+                }),
+                Skip::No | Skip::Properties(..) => {
+                    set.idxs.push(set.tys.len());
+                    set.names.push(match &f.ident {
+                        Some(ident) => external_name(ident, rename, rename_all),
+                        None => i.to_string(),
+                    });
+                    set.docs.push(docs(&f.attrs));
+                    set.annotations.push(meta.tokens()?);
+                    set.identifiers.0.push(id);
+                    set.tys.push(f.ty.clone());
+                    set.excludes.push(skip.properties());
+                    set.exclude_values.push(skip.value());
+                }
+            }
+        }
+
+        Ok(set)
+    }
 }
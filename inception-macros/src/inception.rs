@@ -9,9 +9,9 @@ use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
     token::Comma,
-    Block, Expr, FnArg, GenericParam, Ident, ItemTrait, Meta, Pat, PatIdent, PatType, ReturnType,
-    TraitBound, TraitItem, TraitItemFn, TraitItemType, Type, TypeParam, TypeParamBound, TypePath,
-    Visibility, WherePredicate,
+    Block, Expr, FnArg, GenericArgument, GenericParam, Ident, ItemTrait, Meta, Pat, PatIdent,
+    PatType, PathArguments, ReturnType, TraitBound, TraitItem, TraitItemConst, TraitItemFn,
+    TraitItemType, Type, TypeParam, TypeParamBound, TypePath, Visibility, WherePredicate,
 };
 
 use crate::derive::Identifier;
@@ -19,12 +19,21 @@ use crate::derive::Identifier;
 const NOTHING_FN_IDENT: &str = "nothing";
 const MERGE_FN_IDENT: &str = "merge";
 const MERGE_VAR_FN_IDENT: &str = "merge_variant_field";
+const MERGE_VARIANT_FN_IDENT: &str = "merge_variant";
 const JOIN_FN_IDENT: &str = "join";
+const ENTER_FN_IDENT: &str = "enter";
+const EXIT_FN_IDENT: &str = "exit";
+const MERGE_CONTINUE_FN_IDENT: &str = "merge_continue";
 
 struct Attributes {
     property: Ident,
     comparator: bool,
+    comparator_arity: usize,
+    comparator_with: Option<With>,
     types_only: bool,
+    fallible: bool,
+    short_circuit: bool,
+    field_path: Option<Ident>,
     signature: Option<Signature>,
 }
 
@@ -34,6 +43,14 @@ struct Signature {
     output: Ident,
 }
 
+/// The right-hand operand of a `comparator(with = ...)`, and the mode lining its fields up with
+/// those of `Self`, `ByName` or `ByIndex`.
+#[derive(Clone)]
+struct With {
+    rhs: Ident,
+    by: Ident,
+}
+
 #[derive(Clone)]
 struct InducePhaseSpec {
     ty: Type,
@@ -48,21 +65,26 @@ struct InduceSpec {
     join: InducePhaseSpec,
 }
 
-impl InduceSpec {
-    fn has_where_bounds(&self) -> bool {
-        !self.base.where_preds.is_empty()
-            || !self.merge.where_preds.is_empty()
-            || !self.merge_variant.where_preds.is_empty()
-            || !self.join.where_preds.is_empty()
-    }
-}
-
 #[derive(Clone)]
 struct AssocTypeSpec {
     item: TraitItemType,
     induce: Option<InduceSpec>,
 }
 
+#[derive(Clone)]
+struct InduceConstSpec {
+    base: Expr,
+    merge: Expr,
+    merge_variant: Expr,
+    join: Expr,
+}
+
+#[derive(Clone)]
+struct AssocConstSpec {
+    item: TraitItemConst,
+    induce: Option<InduceConstSpec>,
+}
+
 impl Signature {
     fn parse_ident_expr(expr: Expr, field: &str) -> Result<Ident, syn::Error> {
         let Expr::Path(path_expr) = expr else {
@@ -117,7 +139,12 @@ impl Parse for Attributes {
         let metas = Punctuated::<Meta, Comma>::parse_terminated(input)?;
         let mut property = None;
         let mut comparator = false;
+        let mut comparator_arity = 2;
+        let mut comparator_with = None;
         let mut types_only = false;
+        let mut fallible = false;
+        let mut short_circuit = false;
+        let mut field_path = None;
         let mut signature = None;
         for meta in metas {
             match meta {
@@ -136,6 +163,25 @@ impl Parse for Attributes {
                 Meta::Path(path) if path.is_ident("types") => {
                     types_only = true;
                 }
+                Meta::Path(path) if path.is_ident("fallible") => {
+                    fallible = true;
+                }
+                Meta::Path(path) if path.is_ident("short_circuit") => {
+                    short_circuit = true;
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("field_path") => {
+                    let arg = match &nv.value {
+                        Expr::Path(p) => p.path.get_ident().cloned(),
+                        _ => None,
+                    };
+                    let Some(arg) = arg else {
+                        return Err(syn::Error::new_spanned(
+                            nv.value,
+                            "Expected `field_path` to name the `&mut FieldPath` argument.",
+                        ));
+                    };
+                    field_path = Some(arg);
+                }
                 Meta::NameValue(nv) if nv.path.is_ident("comparator") => {
                     let Expr::Lit(expr_lit) = nv.value else {
                         return Err(syn::Error::new_spanned(
@@ -151,6 +197,75 @@ impl Parse for Attributes {
                     };
                     comparator = v.value;
                 }
+                Meta::List(list) if list.path.is_ident("comparator") => {
+                    comparator = true;
+                    let settings = list.parse_args_with(
+                        Punctuated::<syn::MetaNameValue, Comma>::parse_terminated,
+                    )?;
+                    let (mut rhs, mut by) = (None, None);
+                    for nv in settings {
+                        if nv.path.is_ident("with") {
+                            rhs = Some(Signature::parse_ident_expr(nv.value, "with")?);
+                            continue;
+                        }
+                        if nv.path.is_ident("by") {
+                            by = match Signature::parse_ident_expr(nv.value, "by")? {
+                                by if by == "name" => {
+                                    Some(format_ident!("ByName", span = by.span()))
+                                }
+                                by if by == "index" => {
+                                    Some(format_ident!("ByIndex", span = by.span()))
+                                }
+                                by => {
+                                    return Err(syn::Error::new_spanned(
+                                        by,
+                                        "Expected `by` to be `name` or `index`.",
+                                    ))
+                                }
+                            };
+                            continue;
+                        }
+                        if !nv.path.is_ident("arity") {
+                            return Err(syn::Error::new_spanned(
+                                nv.path,
+                                "Unknown `comparator` setting; expected `arity = ...`, `with = ...` or `by = ...`.",
+                            ));
+                        }
+                        let arity = match &nv.value {
+                            Expr::Lit(syn::ExprLit {
+                                lit: syn::Lit::Int(int),
+                                ..
+                            }) => int.base10_parse::<usize>()?,
+                            _ => 0,
+                        };
+                        if arity < 2 {
+                            return Err(syn::Error::new_spanned(
+                                nv.value,
+                                "Expected `arity` to be an integer of at least 2.",
+                            ));
+                        }
+                        comparator_arity = arity;
+                    }
+                    comparator_with = match (rhs, by) {
+                        (Some(_), _) if comparator_arity != 2 => {
+                            return Err(syn::Error::new_spanned(
+                                list,
+                                "`comparator(with = ...)` compares two operands, so it takes no `arity`.",
+                            ))
+                        }
+                        (Some(rhs), by) => Some(With {
+                            by: by.unwrap_or_else(|| format_ident!("ByName", span = rhs.span())),
+                            rhs,
+                        }),
+                        (None, Some(by)) => {
+                            return Err(syn::Error::new_spanned(
+                                by,
+                                "`by = ...` lines up the fields of `comparator(with = ...)`.",
+                            ))
+                        }
+                        (None, None) => None,
+                    };
+                }
                 Meta::List(list) if list.path.is_ident("signature") => {
                     if signature.is_some() {
                         return Err(syn::Error::new_spanned(
@@ -163,7 +278,7 @@ impl Parse for Attributes {
                 _ => {
                     return Err(syn::Error::new_spanned(
                         meta,
-                        "Invalid `#[inception(...)]` argument. Expected `property = ...`, optional `comparator`, optional `types`, and optional `signature(input = ..., output = ...)`.",
+                        "Invalid `#[inception(...)]` argument. Expected `property = ...`, optional `comparator`, `comparator(arity = ...)` or `comparator(with = ..., by = ...)`, optional `types`, optional `fallible` or `short_circuit`, optional `field_path = ...`, and optional `signature(input = ..., output = ...)`.",
                     ));
                 }
             }
@@ -177,7 +292,12 @@ impl Parse for Attributes {
         Ok(Self {
             property,
             comparator,
+            comparator_arity,
+            comparator_with,
             types_only,
+            fallible,
+            short_circuit,
+            field_path,
             signature,
         })
     }
@@ -510,6 +630,229 @@ fn extract_induce_attr(attrs: &mut Vec<syn::Attribute>) -> Result<Option<InduceS
     Ok(spec)
 }
 
+fn parse_induce_const_spec_from_attr(attr: &syn::Attribute) -> Result<InduceConstSpec, syn::Error> {
+    struct InduceConstEntry {
+        key: Ident,
+        value: Expr,
+    }
+    impl Parse for InduceConstEntry {
+        fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+            let key = input.parse::<Ident>()?;
+            input.parse::<syn::Token![=]>()?;
+            let value = input.parse::<Expr>()?;
+            Ok(Self { key, value })
+        }
+    }
+
+    let nested = attr.parse_args_with(Punctuated::<InduceConstEntry, Comma>::parse_terminated)?;
+    let mut base = None;
+    let mut merge = None;
+    let mut merge_variant = None;
+    let mut join = None;
+    for entry in nested {
+        let slot = match entry.key.to_string().as_str() {
+            "base" => &mut base,
+            "merge" => &mut merge,
+            "merge_variant" => &mut merge_variant,
+            "join" => &mut join,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    entry.key,
+                    "Invalid `#[induce_const(...)]` argument. Expected `base = ...`, `merge = ...`, `merge_variant = ...`, and `join = ...`.",
+                ));
+            }
+        };
+        if slot.is_some() {
+            let msg = format!(
+                "`{}` can only be set once in `#[induce_const(...)]`.",
+                entry.key
+            );
+            return Err(syn::Error::new_spanned(entry.key, msg));
+        }
+        *slot = Some(entry.value);
+    }
+    let missing = |key: &str| {
+        let msg = format!("Missing `{key} = ...` in `#[induce_const(...)]`.");
+        syn::Error::new_spanned(attr, msg)
+    };
+    Ok(InduceConstSpec {
+        base: base.ok_or_else(|| missing("base"))?,
+        merge: merge.ok_or_else(|| missing("merge"))?,
+        merge_variant: merge_variant.ok_or_else(|| missing("merge_variant"))?,
+        join: join.ok_or_else(|| missing("join"))?,
+    })
+}
+
+fn extract_induce_const_attr(
+    attrs: &mut Vec<syn::Attribute>,
+) -> Result<Option<InduceConstSpec>, syn::Error> {
+    let mut spec = None;
+    let mut kept = Vec::with_capacity(attrs.len());
+    for attr in attrs.drain(..) {
+        if attr.path().is_ident("induce_const") {
+            if spec.is_some() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "Only one `#[induce_const(...)]` attribute is allowed per associated const.",
+                ));
+            }
+            spec = Some(parse_induce_const_spec_from_attr(&attr)?);
+        } else {
+            kept.push(attr);
+        }
+    }
+    *attrs = kept;
+    Ok(spec)
+}
+
+/// Rewrites an induced const expression for one of the helper impls: `<X as Trait>::CONST` is
+/// routed through the helper trait and the `Head`, `Tail` and `Fields` placeholders are replaced.
+fn rewrite_induced_const_expr(
+    tokens: proc_macro2::TokenStream,
+    trait_ident: &Ident,
+    const_ident: &Ident,
+    helper_ident: &Ident,
+    placeholders: &[(&Ident, &proc_macro2::TokenStream)],
+) -> proc_macro2::TokenStream {
+    use proc_macro2::{Group, TokenTree};
+
+    let tts = tokens.into_iter().collect::<Vec<_>>();
+    let is_projection = |rest: &[TokenTree]| match rest {
+        [TokenTree::Punct(gt), TokenTree::Punct(c1), TokenTree::Punct(c2), TokenTree::Ident(id), ..] => {
+            gt.as_char() == '>' && c1.as_char() == ':' && c2.as_char() == ':' && id == const_ident
+        }
+        _ => false,
+    };
+    let mut out = proc_macro2::TokenStream::new();
+    for (i, tt) in tts.iter().enumerate() {
+        match tt {
+            TokenTree::Group(g) => {
+                let stream = rewrite_induced_const_expr(
+                    g.stream(),
+                    trait_ident,
+                    const_ident,
+                    helper_ident,
+                    placeholders,
+                );
+                let mut group = Group::new(g.delimiter(), stream);
+                group.set_span(g.span());
+                out.extend([TokenTree::Group(group)]);
+            }
+            TokenTree::Ident(id) if id == trait_ident && is_projection(&tts[i + 1..]) => {
+                out.extend(quote! { #helper_ident });
+            }
+            TokenTree::Ident(id) => match placeholders.iter().find(|(p, _)| id == *p) {
+                Some((_, replacement)) => out.extend((*replacement).clone()),
+                None => out.extend([tt.clone()]),
+            },
+            _ => out.extend([tt.clone()]),
+        }
+    }
+    out
+}
+
+struct InducedConsts {
+    helpers: Vec<proc_macro2::TokenStream>,
+    impl_items: Vec<proc_macro2::TokenStream>,
+    blanket_where: Vec<proc_macro2::TokenStream>,
+}
+
+/// Generates the helper traits that induct `#[induce_const]` values over the type-level fields,
+/// along with the items and bounds needed by the blanket impl. The helpers live in `mod_ident`
+/// and rely on its `Wrap`.
+fn induced_consts(
+    consts: &[AssocConstSpec],
+    trait_ident: &Ident,
+    property_ident: &Ident,
+    mod_ident: &Ident,
+) -> InducedConsts {
+    let head = format_ident!("Head");
+    let tail = format_ident!("Tail");
+    let fields = format_ident!("Fields");
+    let head_ty = quote! { H };
+    let tail_ty = quote! { Wrap<F> };
+    let fields_ty = quote! { Wrap<<T as Inception<super::#property_ident>>::TyFields> };
+
+    let mut induced = InducedConsts {
+        helpers: vec![],
+        impl_items: vec![],
+        blanket_where: vec![],
+    };
+    for c in consts.iter() {
+        let Some(induce) = c.induce.as_ref() else {
+            continue;
+        };
+        let ident = &c.item.ident;
+        let ty = &c.item.ty;
+        let helper_ident = format_ident!("__InceptionInduceConst{}", ident);
+        let rewrite = |expr: &Expr, placeholders: &[(&Ident, &proc_macro2::TokenStream)]| {
+            rewrite_induced_const_expr(
+                quote! { #expr },
+                trait_ident,
+                ident,
+                &helper_ident,
+                placeholders,
+            )
+        };
+        let base = rewrite(&induce.base, &[]);
+        let merge = rewrite(&induce.merge, &[(&head, &head_ty), (&tail, &tail_ty)]);
+        let merge_variant = rewrite(
+            &induce.merge_variant,
+            &[(&head, &head_ty), (&tail, &tail_ty)],
+        );
+        let join = rewrite(&induce.join, &[(&fields, &fields_ty)]);
+
+        induced.helpers.push(quote! {
+            pub trait #helper_ident<P: TruthValue = <Self as IsPrimitive<super::#property_ident>>::Is> {
+                const #ident: #ty;
+            }
+
+            impl<T> #helper_ident<True> for T
+            where
+                T: super::#trait_ident + IsPrimitive<super::#property_ident, Is = True>,
+            {
+                const #ident: #ty = <T as super::#trait_ident>::#ident;
+            }
+
+            impl #helper_ident<False> for Wrap<List<()>> {
+                const #ident: #ty = #base;
+            }
+
+            impl<H, S, const IDX: usize, F> #helper_ident<False> for Wrap<List<(TyField<H, S, IDX>, F)>>
+            where
+                H: IsPrimitive<super::#property_ident> + #helper_ident,
+                Wrap<F>: #helper_ident<False>,
+            {
+                const #ident: #ty = #merge;
+            }
+
+            impl<H, S, const VAR_IDX: usize, const IDX: usize, F> #helper_ident<False>
+                for Wrap<List<(VarTyField<H, S, VAR_IDX, IDX>, F)>>
+            where
+                H: IsPrimitive<super::#property_ident> + #helper_ident,
+                Wrap<F>: #helper_ident<False>,
+            {
+                const #ident: #ty = #merge_variant;
+            }
+
+            impl<T> #helper_ident<False> for T
+            where
+                T: Inception<super::#property_ident> + Meta,
+                #fields_ty: #helper_ident<False>,
+            {
+                const #ident: #ty = #join;
+            }
+        });
+        induced.impl_items.push(quote! {
+            const #ident: #ty = <T as #mod_ident::#helper_ident<::inception::False>>::#ident;
+        });
+        induced.blanket_where.push(quote! {
+            T: #mod_ident::#helper_ident<::inception::False>,
+        });
+    }
+    induced
+}
+
 pub enum Kind {
     Ty,
     Ref,
@@ -603,7 +946,7 @@ impl Kind {
             Self::Ty => quote! { let fields = <T as Inception<#property>>::ty_fields(); },
             Self::Ref => quote! { let fields = self.fields(); },
             Self::Mut => quote! {
-                let mut header = VariantHeader;
+                let mut header = VariantHeader::default();
                 let mut fields = self.fields_mut(&mut header);
             },
             Self::Owned => quote! {
@@ -612,6 +955,58 @@ impl Kind {
         }
     }
 
+    /// Like `fields_fn`, but with the fields of enums grouped by variant for `merge_variant`.
+    fn grouped_fields_fn(&self, property: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let group = quote! {
+            ::inception::Group::<#property, T, <T as ::inception::DataType>::Ty>::group
+        };
+        match self {
+            Self::Ty => quote! { let fields = #group(<T as Inception<#property>>::ty_fields()); },
+            Self::Ref => quote! { let fields = #group(self.fields()); },
+            Self::Mut => quote! {
+                let mut header = VariantHeader::default();
+                let mut fields = #group(self.fields_mut(&mut header));
+            },
+            Self::Owned => quote! {
+                let fields = #group(self.into_fields());
+            },
+        }
+    }
+
+    /// The variant of a `var_field` holding a field of the active variant.
+    fn present_variant(&self) -> Ident {
+        match self {
+            Self::Ty => format_ident!("Ty"),
+            Self::Ref => format_ident!("Ref"),
+            Self::Mut => format_ident!("Mut"),
+            Self::Owned => format_ident!("Owned"),
+        }
+    }
+
+    /// Takes apart one variant of grouped enum fields into its header, its remaining fields and
+    /// the variants after it.
+    fn split_variant(&self) -> proc_macro2::TokenStream {
+        let var_field = self.var_field();
+        match self {
+            Self::Ty => quote! {
+                let l = <#var_field<H, S, VAR_IDX, IDX> as Phantom>::phantom();
+                let v = <F as Phantom>::phantom();
+                let r = <Rest as Phantom>::phantom();
+            },
+            Self::Ref => quote! {
+                let ::inception::Variants(List((List((l, v)), r))) = self.0;
+                let l = l.clone();
+            },
+            Self::Mut => quote! {
+                let ::inception::Variants(List((List((l, v)), r))) = &mut *self.0;
+                let l = l.take();
+            },
+            Self::Owned => quote! {
+                let ::inception::Variants(List((List((l, v)), r))) = self.0;
+            },
+        }
+    }
+
     fn comparator_fields_fn(
         &self,
         ident: &Ident,
@@ -621,7 +1016,7 @@ impl Kind {
             Self::Ty => quote! { let #ident = <T as Inception<#property>>::ty_fields(); },
             Self::Ref => quote! { let #ident = #ident.fields(); },
             Self::Mut => quote! {
-                let mut header = VariantHeader;
+                let mut header = VariantHeader::default();
                 let mut #ident = #ident.fields_mut(&mut header);
             },
             Self::Owned => quote! {
@@ -897,7 +1292,11 @@ impl ParseFn for TraitItemFn {
         NOTHING_FN_IDENT,
         MERGE_FN_IDENT,
         MERGE_VAR_FN_IDENT,
+        MERGE_VARIANT_FN_IDENT,
         JOIN_FN_IDENT,
+        ENTER_FN_IDENT,
+        EXIT_FN_IDENT,
+        MERGE_CONTINUE_FN_IDENT,
     ];
     fn is_reserved(&self) -> bool {
         let name = self.sig.ident.to_string();
@@ -1031,100 +1430,525 @@ impl ParseFn for TraitItemFn {
     }
 }
 
-struct GenericBounds {
-    bounds: Vec<TypeParamBound>,
-}
-impl GenericBounds {
-    fn is_empty(&self) -> bool {
-        self.bounds.is_empty()
-    }
-
-    fn use_inner_trait(
-        mut self,
-        trait_ident: &Ident,
-        inductive_ident: &Ident,
-        mod_ident: &Ident,
-        flow_input_ident: Option<&Ident>,
-        flow_output_ident: Option<&Ident>,
-    ) -> Self {
-        fn is_self_assoc_path(qself: &Option<syn::QSelf>, path: &syn::Path, assoc: &Ident) -> bool {
-            let direct_self_assoc = path.segments.len() == 2
-                && path
-                    .segments
-                    .first()
-                    .map(|s| s.ident == "Self")
-                    .unwrap_or(false)
-                && path
-                    .segments
-                    .last()
-                    .map(|s| s.ident == *assoc)
-                    .unwrap_or(false);
-            if direct_self_assoc {
-                return true;
+fn replace_type_ident(ty: &mut Type, target: &Ident, replacement: &Type) {
+    match ty {
+        Type::Path(TypePath { qself, path }) => {
+            if qself.is_none() && path.is_ident(target) {
+                *ty = replacement.clone();
+                return;
             }
-            let Some(last) = path.segments.last() else {
-                return false;
-            };
-            if last.ident != *assoc {
-                return false;
+            if let Some(q) = qself {
+                replace_type_ident(&mut q.ty, target, replacement);
             }
-            let Some(q) = qself else {
-                return false;
-            };
-            matches!(
-                q.ty.as_ref(),
-                Type::Path(TypePath { qself: None, path })
-                if path.is_ident("Self")
-            )
-        }
-
-        fn replace_flow_type(ty: &mut Type, flow_in: Option<&Ident>, flow_out: Option<&Ident>) {
-            match ty {
-                Type::Path(TypePath { qself, path }) => {
-                    if let Some(flow) = flow_in {
-                        if path.is_ident(flow) || is_self_assoc_path(qself, path, flow) {
-                            *ty = syn::parse_quote!(In);
-                            return;
+            for seg in path.segments.iter_mut() {
+                match &mut seg.arguments {
+                    syn::PathArguments::AngleBracketed(args) => {
+                        for arg in args.args.iter_mut() {
+                            match arg {
+                                syn::GenericArgument::Type(inner) => {
+                                    replace_type_ident(inner, target, replacement)
+                                }
+                                syn::GenericArgument::AssocType(assoc) => {
+                                    replace_type_ident(&mut assoc.ty, target, replacement)
+                                }
+                                _ => {}
+                            }
                         }
                     }
-                    if let Some(flow) = flow_out {
-                        if path.is_ident(flow) || is_self_assoc_path(qself, path, flow) {
-                            *ty = syn::parse_quote!(Out);
-                            return;
+                    syn::PathArguments::Parenthesized(args) => {
+                        for input in args.inputs.iter_mut() {
+                            replace_type_ident(input, target, replacement);
+                        }
+                        if let ReturnType::Type(_, out) = &mut args.output {
+                            replace_type_ident(out.as_mut(), target, replacement);
                         }
                     }
-                    if let Some(q) = qself {
-                        replace_flow_type(&mut q.ty, flow_in, flow_out);
+                    syn::PathArguments::None => {}
+                }
+            }
+        }
+        Type::Reference(r) => replace_type_ident(r.elem.as_mut(), target, replacement),
+        Type::Ptr(p) => replace_type_ident(p.elem.as_mut(), target, replacement),
+        Type::Slice(s) => replace_type_ident(s.elem.as_mut(), target, replacement),
+        Type::Array(a) => replace_type_ident(a.elem.as_mut(), target, replacement),
+        Type::Tuple(t) => {
+            for elem in t.elems.iter_mut() {
+                replace_type_ident(elem, target, replacement);
+            }
+        }
+        Type::Paren(p) => replace_type_ident(p.elem.as_mut(), target, replacement),
+        Type::Group(g) => replace_type_ident(g.elem.as_mut(), target, replacement),
+        _ => {}
+    }
+}
+fn replace_type_ident_in_path_args(
+    args: &mut syn::PathArguments,
+    target: &Ident,
+    replacement: &Type,
+) {
+    match args {
+        syn::PathArguments::AngleBracketed(ab) => {
+            for arg in ab.args.iter_mut() {
+                match arg {
+                    syn::GenericArgument::Type(inner) => {
+                        replace_type_ident(inner, target, replacement)
                     }
-                    for seg in path.segments.iter_mut() {
-                        match &mut seg.arguments {
-                            syn::PathArguments::AngleBracketed(args) => {
-                                for arg in args.args.iter_mut() {
-                                    match arg {
-                                        syn::GenericArgument::Type(inner) => {
-                                            replace_flow_type(inner, flow_in, flow_out);
-                                        }
-                                        syn::GenericArgument::AssocType(assoc) => {
-                                            replace_flow_type(&mut assoc.ty, flow_in, flow_out);
-                                        }
-                                        _ => {}
-                                    }
-                                }
-                            }
-                            syn::PathArguments::Parenthesized(args) => {
-                                for input in args.inputs.iter_mut() {
-                                    replace_flow_type(input, flow_in, flow_out);
-                                }
-                                if let ReturnType::Type(_, out) = &mut args.output {
-                                    replace_flow_type(out.as_mut(), flow_in, flow_out);
+                    syn::GenericArgument::AssocType(assoc) => {
+                        replace_type_ident(&mut assoc.ty, target, replacement)
+                    }
+                    syn::GenericArgument::Constraint(constraint) => {
+                        for b in constraint.bounds.iter_mut() {
+                            if let TypeParamBound::Trait(tb) = b {
+                                for seg in tb.path.segments.iter_mut() {
+                                    replace_type_ident_in_path_args(
+                                        &mut seg.arguments,
+                                        target,
+                                        replacement,
+                                    );
                                 }
                             }
-                            syn::PathArguments::None => {}
                         }
                     }
+                    _ => {}
                 }
-                Type::Reference(r) => replace_flow_type(r.elem.as_mut(), flow_in, flow_out),
-                Type::Ptr(p) => replace_flow_type(p.elem.as_mut(), flow_in, flow_out),
+            }
+        }
+        syn::PathArguments::Parenthesized(pb) => {
+            for input in pb.inputs.iter_mut() {
+                replace_type_ident(input, target, replacement);
+            }
+            if let ReturnType::Type(_, out) = &mut pb.output {
+                replace_type_ident(out.as_mut(), target, replacement);
+            }
+        }
+        syn::PathArguments::None => {}
+    }
+}
+fn substitute_where_preds(
+    preds: &[WherePredicate],
+    replacements: &[(&Ident, &Type)],
+) -> Vec<WherePredicate> {
+    let mut out = preds.to_vec();
+    for pred in out.iter_mut() {
+        if let WherePredicate::Type(tp) = pred {
+            for (target, replacement) in replacements.iter() {
+                replace_type_ident(&mut tp.bounded_ty, target, replacement);
+                for b in tp.bounds.iter_mut() {
+                    if let TypeParamBound::Trait(tb) = b {
+                        for seg in tb.path.segments.iter_mut() {
+                            replace_type_ident_in_path_args(
+                                &mut seg.arguments,
+                                target,
+                                replacement,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+/// The argument named `ident`, if any.
+fn named_arg(args: &Punctuated<FnArg, Comma>, ident: &Ident) -> Option<Ident> {
+    args.iter().find_map(|arg| match arg {
+        FnArg::Typed(PatType { pat, .. }) => match pat.deref() {
+            Pat::Ident(PatIdent { ident: id, .. }) if id == ident => Some(id.clone()),
+            _ => None,
+        },
+        FnArg::Receiver(_) => None,
+    })
+}
+
+/// The first use in `body` of `tail_arg`, or of another argument of type `tail_ty` such as the
+/// tails of further comparator operands. Bindings of the same name in closures, `let`s, match arms
+/// and loops shadow the tail, and fields or methods named like it are no use of it.
+fn tail_use(
+    body: &Block,
+    tail_arg: &Ident,
+    tail_ty: &Ident,
+    args: &Punctuated<FnArg, Comma>,
+) -> Option<Ident> {
+    use syn::visit::{self, Visit};
+
+    /// The identifiers bound by `pat`.
+    fn bindings(pat: &Pat) -> Vec<Ident> {
+        struct Bindings(Vec<Ident>);
+        impl Visit<'_> for Bindings {
+            fn visit_pat_ident(&mut self, p: &PatIdent) {
+                self.0.push(p.ident.clone());
+                visit::visit_pat_ident(self, p);
+            }
+        }
+        let mut b = Bindings(vec![]);
+        b.visit_pat(pat);
+        b.0
+    }
+
+    struct TailUse<'a> {
+        tails: Vec<&'a Ident>,
+        shadowed: Vec<Ident>,
+        found: Option<Ident>,
+    }
+    impl TailUse<'_> {
+        /// Visits `f` with the bindings of `pats` in scope.
+        fn scoped<'p>(
+            &mut self,
+            pats: impl IntoIterator<Item = &'p Pat>,
+            f: impl FnOnce(&mut Self),
+        ) {
+            let len = self.shadowed.len();
+            for pat in pats {
+                self.shadowed.extend(bindings(pat));
+            }
+            f(self);
+            self.shadowed.truncate(len);
+        }
+
+        fn use_of(&mut self, id: &Ident) {
+            if self.found.is_none() && self.tails.contains(&id) && !self.shadowed.contains(id) {
+                self.found = Some(id.clone());
+            }
+        }
+    }
+    impl<'ast> Visit<'ast> for TailUse<'_> {
+        fn visit_expr_path(&mut self, e: &'ast syn::ExprPath) {
+            if let (None, Some(id)) = (&e.qself, e.path.get_ident()) {
+                self.use_of(id);
+            }
+        }
+        fn visit_block(&mut self, b: &'ast Block) {
+            let len = self.shadowed.len();
+            for stmt in &b.stmts {
+                match stmt {
+                    syn::Stmt::Local(local) => {
+                        if let Some(init) = &local.init {
+                            self.visit_local_init(init);
+                        }
+                        self.shadowed.extend(bindings(&local.pat));
+                    }
+                    stmt => self.visit_stmt(stmt),
+                }
+            }
+            self.shadowed.truncate(len);
+        }
+        fn visit_expr_closure(&mut self, c: &'ast syn::ExprClosure) {
+            self.scoped(&c.inputs, |v| v.visit_expr(&c.body));
+        }
+        fn visit_arm(&mut self, arm: &'ast syn::Arm) {
+            self.scoped([&arm.pat], |v| {
+                if let Some((_, guard)) = &arm.guard {
+                    v.visit_expr(guard);
+                }
+                v.visit_expr(&arm.body);
+            });
+        }
+        fn visit_expr_for_loop(&mut self, e: &'ast syn::ExprForLoop) {
+            self.visit_expr(&e.expr);
+            self.scoped([e.pat.deref()], |v| v.visit_block(&e.body));
+        }
+        fn visit_expr_if(&mut self, e: &'ast syn::ExprIf) {
+            match e.cond.deref() {
+                Expr::Let(cond) => {
+                    self.visit_expr(&cond.expr);
+                    self.scoped([cond.pat.deref()], |v| v.visit_block(&e.then_branch));
+                }
+                cond => {
+                    self.visit_expr(cond);
+                    self.visit_block(&e.then_branch);
+                }
+            }
+            if let Some((_, els)) = &e.else_branch {
+                self.visit_expr(els);
+            }
+        }
+        fn visit_expr_while(&mut self, e: &'ast syn::ExprWhile) {
+            match e.cond.deref() {
+                Expr::Let(cond) => {
+                    self.visit_expr(&cond.expr);
+                    self.scoped([cond.pat.deref()], |v| v.visit_block(&e.body));
+                }
+                cond => {
+                    self.visit_expr(cond);
+                    self.visit_block(&e.body);
+                }
+            }
+        }
+        // Nested items can't see the arguments of the step.
+        fn visit_item(&mut self, _: &'ast syn::Item) {}
+        // Macro arguments are visited as expressions where they parse as such, e.g. `vec![r]`,
+        // and scanned token by token otherwise.
+        fn visit_macro(&mut self, m: &'ast syn::Macro) {
+            fn scan(v: &mut TailUse<'_>, tokens: proc_macro2::TokenStream) {
+                for tt in tokens {
+                    match tt {
+                        proc_macro2::TokenTree::Ident(id) => v.use_of(&id),
+                        proc_macro2::TokenTree::Group(g) => scan(v, g.stream()),
+                        _ => {}
+                    }
+                }
+            }
+            match m.parse_body_with(Punctuated::<Expr, Comma>::parse_terminated) {
+                Ok(exprs) => exprs.iter().for_each(|e| self.visit_expr(e)),
+                Err(_) => scan(self, m.tokens.clone()),
+            }
+        }
+    }
+
+    let mut tails = vec![tail_arg];
+    tails.extend(args.iter().filter_map(|arg| match arg {
+        FnArg::Typed(PatType { pat, ty, .. }) => match (pat.deref(), ty.deref()) {
+            (Pat::Ident(p), Type::Path(tp)) if tp.path.is_ident(tail_ty) => Some(&p.ident),
+            _ => None,
+        },
+        FnArg::Receiver(_) => None,
+    }));
+    let mut v = TailUse {
+        tails,
+        shadowed: vec![],
+        found: None,
+    };
+    v.visit_block(body);
+    v.found
+}
+
+/// The `C` of a behavior returning `ControlFlow<B, C>`, unless it is left to its default.
+fn continue_ty(fn_ret: &ReturnType) -> Option<Type> {
+    let ReturnType::Type(_, ty) = fn_ret else {
+        return None;
+    };
+    let Type::Path(tp) = ty.deref() else {
+        return None;
+    };
+    let flow = tp.path.segments.last()?;
+    let PathArguments::AngleBracketed(args) = &flow.arguments else {
+        return None;
+    };
+    match args.args.iter().nth(1) {
+        Some(GenericArgument::Type(c)) => Some(c.clone()),
+        _ => None,
+    }
+}
+
+fn is_unit(ty: &Type) -> bool {
+    matches!(ty, Type::Tuple(t) if t.elems.is_empty())
+}
+
+/// The `T` of a `fallible` behavior returning `Result<T, FieldError<E>>`.
+fn fallible_ok_ty(fn_ret: &ReturnType) -> Option<Type> {
+    let ReturnType::Type(_, ty) = fn_ret else {
+        return None;
+    };
+    let Type::Path(tp) = ty.deref() else {
+        return None;
+    };
+    let result = tp.path.segments.last()?;
+    let PathArguments::AngleBracketed(args) = &result.arguments else {
+        return None;
+    };
+    let mut args = args.args.iter();
+    let (Some(GenericArgument::Type(ok)), Some(GenericArgument::Type(Type::Path(err))), None) =
+        (args.next(), args.next(), args.next())
+    else {
+        return None;
+    };
+    (result.ident == "Result" && err.path.segments.last()?.ident == "FieldError")
+        .then(|| ok.clone())
+}
+
+/// The `X` of a behavior returning `Self::X<'_>` or `<Self as Trait>::X<'_>`.
+fn self_gat_ret(ty: &Type) -> Option<&Ident> {
+    let Type::Path(TypePath { qself, path }) = ty else {
+        return None;
+    };
+    let self_qualified = match qself {
+        Some(q) => matches!(
+            q.ty.as_ref(),
+            Type::Path(TypePath { qself: None, path }) if path.is_ident("Self")
+        ),
+        None => path.segments.len() == 2 && path.segments[0].ident == "Self",
+    };
+    let last = path.segments.last()?;
+    let PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None;
+    };
+    let single_lifetime =
+        args.args.len() == 1 && matches!(args.args[0], GenericArgument::Lifetime(_));
+    (self_qualified && single_lifetime).then_some(&last.ident)
+}
+
+/// Replaces the projections `<Source as Trait>::Assoc<..>` in an induced type by `replacement`.
+fn replace_assoc_projection(
+    ty: &mut Type,
+    trait_ident: &Ident,
+    assoc_ident: &Ident,
+    source: &Ident,
+    replacement: &Type,
+) {
+    let recurse =
+        |ty: &mut Type| replace_assoc_projection(ty, trait_ident, assoc_ident, source, replacement);
+    match ty {
+        Type::Path(TypePath { qself, path }) => {
+            let projected = qself.as_ref().is_some_and(|q| {
+                matches!(q.ty.as_ref(), Type::Path(TypePath { qself: None, path }) if path.is_ident(source))
+            }) && path.segments.len() >= 2
+                && path.segments.last().is_some_and(|s| s.ident == *assoc_ident)
+                && path.segments[path.segments.len() - 2].ident == *trait_ident;
+            if projected {
+                *ty = replacement.clone();
+                return;
+            }
+            if let Some(q) = qself {
+                recurse(&mut q.ty);
+            }
+            for seg in path.segments.iter_mut() {
+                if let PathArguments::AngleBracketed(args) = &mut seg.arguments {
+                    for arg in args.args.iter_mut() {
+                        match arg {
+                            GenericArgument::Type(inner) => recurse(inner),
+                            GenericArgument::AssocType(assoc) => recurse(&mut assoc.ty),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+        Type::Reference(r) => recurse(r.elem.as_mut()),
+        Type::Ptr(p) => recurse(p.elem.as_mut()),
+        Type::Slice(s) => recurse(s.elem.as_mut()),
+        Type::Array(a) => recurse(a.elem.as_mut()),
+        Type::Tuple(t) => t.elems.iter_mut().for_each(recurse),
+        Type::Paren(p) => recurse(p.elem.as_mut()),
+        Type::Group(g) => recurse(g.elem.as_mut()),
+        _ => {}
+    }
+}
+
+/// Renames the lifetime `from` to `to` throughout `tokens`.
+fn rename_lifetime(
+    tokens: proc_macro2::TokenStream,
+    from: &syn::Lifetime,
+    to: &syn::Lifetime,
+) -> proc_macro2::TokenStream {
+    use proc_macro2::{Group, TokenTree};
+    let mut tokens = tokens.into_iter().peekable();
+    let mut renamed = Vec::new();
+    while let Some(tt) = tokens.next() {
+        match tt {
+            TokenTree::Group(g) => {
+                let mut group = Group::new(g.delimiter(), rename_lifetime(g.stream(), from, to));
+                group.set_span(g.span());
+                renamed.push(TokenTree::Group(group));
+            }
+            TokenTree::Punct(p) if p.as_char() == '\'' => {
+                renamed.push(TokenTree::Punct(p));
+                if matches!(tokens.peek(), Some(TokenTree::Ident(id)) if *id == from.ident) {
+                    tokens.next();
+                    renamed.push(TokenTree::Ident(to.ident.clone()));
+                }
+            }
+            tt => renamed.push(tt),
+        }
+    }
+    renamed.into_iter().collect()
+}
+
+#[derive(Clone)]
+struct GenericBounds {
+    bounds: Vec<TypeParamBound>,
+}
+impl GenericBounds {
+    fn is_empty(&self) -> bool {
+        self.bounds.is_empty()
+    }
+
+    fn use_inner_trait(
+        mut self,
+        trait_ident: &Ident,
+        inductive_ident: &Ident,
+        mod_ident: &Ident,
+        flow_input_ident: Option<&Ident>,
+        flow_output_ident: Option<&Ident>,
+    ) -> Self {
+        fn is_self_assoc_path(qself: &Option<syn::QSelf>, path: &syn::Path, assoc: &Ident) -> bool {
+            let direct_self_assoc = path.segments.len() == 2
+                && path
+                    .segments
+                    .first()
+                    .map(|s| s.ident == "Self")
+                    .unwrap_or(false)
+                && path
+                    .segments
+                    .last()
+                    .map(|s| s.ident == *assoc)
+                    .unwrap_or(false);
+            if direct_self_assoc {
+                return true;
+            }
+            let Some(last) = path.segments.last() else {
+                return false;
+            };
+            if last.ident != *assoc {
+                return false;
+            }
+            let Some(q) = qself else {
+                return false;
+            };
+            matches!(
+                q.ty.as_ref(),
+                Type::Path(TypePath { qself: None, path })
+                if path.is_ident("Self")
+            )
+        }
+
+        fn replace_flow_type(ty: &mut Type, flow_in: Option<&Ident>, flow_out: Option<&Ident>) {
+            match ty {
+                Type::Path(TypePath { qself, path }) => {
+                    if let Some(flow) = flow_in {
+                        if path.is_ident(flow) || is_self_assoc_path(qself, path, flow) {
+                            *ty = syn::parse_quote!(In);
+                            return;
+                        }
+                    }
+                    if let Some(flow) = flow_out {
+                        if path.is_ident(flow) || is_self_assoc_path(qself, path, flow) {
+                            *ty = syn::parse_quote!(Out);
+                            return;
+                        }
+                    }
+                    if let Some(q) = qself {
+                        replace_flow_type(&mut q.ty, flow_in, flow_out);
+                    }
+                    for seg in path.segments.iter_mut() {
+                        match &mut seg.arguments {
+                            syn::PathArguments::AngleBracketed(args) => {
+                                for arg in args.args.iter_mut() {
+                                    match arg {
+                                        syn::GenericArgument::Type(inner) => {
+                                            replace_flow_type(inner, flow_in, flow_out);
+                                        }
+                                        syn::GenericArgument::AssocType(assoc) => {
+                                            replace_flow_type(&mut assoc.ty, flow_in, flow_out);
+                                        }
+                                        _ => {}
+                                    }
+                                }
+                            }
+                            syn::PathArguments::Parenthesized(args) => {
+                                for input in args.inputs.iter_mut() {
+                                    replace_flow_type(input, flow_in, flow_out);
+                                }
+                                if let ReturnType::Type(_, out) = &mut args.output {
+                                    replace_flow_type(out.as_mut(), flow_in, flow_out);
+                                }
+                            }
+                            syn::PathArguments::None => {}
+                        }
+                    }
+                }
+                Type::Reference(r) => replace_flow_type(r.elem.as_mut(), flow_in, flow_out),
+                Type::Ptr(p) => replace_flow_type(p.elem.as_mut(), flow_in, flow_out),
                 Type::Slice(s) => replace_flow_type(s.elem.as_mut(), flow_in, flow_out),
                 Type::Array(a) => replace_flow_type(a.elem.as_mut(), flow_in, flow_out),
                 Type::Tuple(t) => {
@@ -1248,6 +2072,40 @@ impl GenericBounds {
         self
     }
 
+    /// Bounds of a `comparator(with = ...)` step, where `Trait<Ret = ..>` compares against `other`
+    /// and is primitive as told by `is_primitive`.
+    fn use_heterogeneous_trait(
+        mut self,
+        trait_ident: &Ident,
+        mod_ident: &Ident,
+        is_primitive: &proc_macro2::TokenStream,
+        other: &proc_macro2::TokenStream,
+    ) -> Self {
+        for bound in self.bounds.iter_mut() {
+            let TypeParamBound::Trait(TraitBound { path, .. }) = bound else {
+                continue;
+            };
+            let Some(seg) = path
+                .segments
+                .first()
+                .filter(|seg| &seg.ident == trait_ident)
+            else {
+                continue;
+            };
+            let assoc = match &seg.arguments {
+                PathArguments::AngleBracketed(args) => args
+                    .args
+                    .iter()
+                    .filter(|arg| !matches!(arg, GenericArgument::Type(_)))
+                    .cloned()
+                    .collect(),
+                _ => vec![],
+            };
+            *path = syn::parse_quote!(#mod_ident::Inductive<#is_primitive, (), (), #other #(, #assoc)*>);
+        }
+        self
+    }
+
     fn into_tokens(self) -> proc_macro2::TokenStream {
         let v = self.bounds;
         quote! { #(+ #v)* }
@@ -1258,15 +2116,23 @@ enum Step {
     Base(Nothing),
     Merge(MergeField),
     Enum(MergeVar),
+    Variant(MergeVariant),
     Join(Join),
+    Enter(Hook),
+    Exit(Hook),
+    Continue(MergeContinue),
 }
 impl Step {
-    fn parse(f: &TraitItemFn) -> Result<Self, &str> {
-        match f.sig.ident.to_string().as_str() {
+    fn parse<'a>(f: &'a TraitItemFn, step: &str) -> Result<Self, &'a str> {
+        match step {
             s if s == NOTHING_FN_IDENT => Ok(Self::Base(Nothing::parse(f)?)),
             s if s == MERGE_FN_IDENT => Ok(Self::Merge(MergeField::parse(f)?)),
             s if s == MERGE_VAR_FN_IDENT => Ok(Self::Enum(MergeVar::parse(f)?)),
+            s if s == MERGE_VARIANT_FN_IDENT => Ok(Self::Variant(MergeVariant::parse(f)?)),
             s if s == JOIN_FN_IDENT => Ok(Self::Join(Join::parse(f)?)),
+            s if s == ENTER_FN_IDENT => Ok(Self::Enter(Hook::parse(f)?)),
+            s if s == EXIT_FN_IDENT => Ok(Self::Exit(Hook::parse(f)?)),
+            s if s == MERGE_CONTINUE_FN_IDENT => Ok(Self::Continue(MergeContinue::parse(f)?)),
             _ => Err("Unexpected step"),
         }
     }
@@ -1335,47 +2201,28 @@ impl MergeField {
         })
     }
 
-    fn validate_comparator(&mut self) -> Result<(), &str> {
-        self.merge_arg_idents.clear();
-        let (Some(head), Some(tail), None) = (
-            self.merge_args.get(0),
-            self.merge_args.get(1),
-            self.merge_args.get(2),
-        ) else {
-            return Err("Expected exactly 2 additional arguments in comparator merge.");
-        };
-
-        let (
-            FnArg::Typed(PatType {
-                pat: lp, ty: lty, ..
-            }),
-            FnArg::Typed(PatType {
-                pat: rp, ty: rty, ..
-            }),
-        ) = (head, tail)
-        else {
-            return Err("Expected additional comparator arguments to be typed patterns.");
-        };
-        let (Pat::Ident(li), Pat::Ident(ri)) = (lp.deref(), rp.deref()) else {
-            return Err("Expected ident patterns for additional comparator arguments");
-        };
-        self.merge_arg_idents.push(li.ident.clone());
-        self.merge_arg_idents.push(ri.ident.clone());
+    /// The first use of a tail argument in the body.
+    fn tail_use(&self) -> Option<Ident> {
+        tail_use(
+            &self.merge_body,
+            &self.merge_fields_arg,
+            &self.merge_fields_arg_ty,
+            &self.merge_args,
+        )
+    }
 
-        let (Type::Path(TypePath { path: ltpath, .. }), Type::Path(TypePath { path: rtpath, .. })) =
-            (lty.deref(), rty.deref())
-        else {
-            return Err(
-                "Expected head field and tail fields as types for additional comparator arguments",
-            );
-        };
-        if !ltpath.is_ident(&self.merge_head_arg_ty) || !rtpath.is_ident(&self.merge_fields_arg_ty)
-        {
-            return Err(
-                "Expected head field and tail fields as types for additional comparator arguments",
-            );
-        }
+    fn validate_comparator(&mut self, arity: usize) -> Result<(), String> {
+        self.merge_arg_idents = comparator_operands(
+            &self.merge_args,
+            &self.merge_head_arg_ty,
+            &self.merge_fields_arg_ty,
+            arity,
+        )?;
+        Ok(())
+    }
 
+    fn validate_heterogeneous(&mut self, rhs: &Ident) -> Result<(), String> {
+        self.merge_arg_idents = heterogeneous_operands(&self.merge_args, rhs)?;
         Ok(())
     }
 }
@@ -1389,7 +2236,6 @@ struct MergeVar {
     #[allow(unused)]
     merge_var_head_arg_ty: Ident,
     merge_var_fields_arg: Ident,
-    #[allow(unused)]
     merge_var_fields_arg_ty: Ident,
     merge_var_field_head_bounds: GenericBounds,
     merge_var_fields_bounds: GenericBounds,
@@ -1424,49 +2270,125 @@ impl MergeVar {
         })
     }
 
-    fn validate_comparator(&mut self) -> Result<(), &str> {
-        self.merge_var_arg_idents.clear();
-        let (Some(head), Some(tail), None) = (
-            self.merge_var_args.get(0),
-            self.merge_var_args.get(1),
-            self.merge_var_args.get(2),
-        ) else {
-            return Err("Expected exactly 2 additional arguments in comparator merge.");
-        };
+    /// Stands in for an omitted `merge_variant_field` by running the `merge` body on the fields
+    /// of the active variant, the others being skipped by the generated impls.
+    fn from_merge(merge: &MergeField) -> Self {
+        Self {
+            merge_var_body: merge.merge_body.clone(),
+            merge_var_ret: merge.merge_ret.clone(),
+            merge_var_head_ident: merge.merge_head_ident.clone(),
+            merge_var_fields_ident: merge.merge_fields_ident.clone(),
+            merge_var_extra_generics: merge.merge_extra_generics.clone(),
+            merge_var_head_arg: merge.merge_head_arg.clone(),
+            merge_var_head_arg_ty: merge.merge_head_arg_ty.clone(),
+            merge_var_fields_arg: merge.merge_fields_arg.clone(),
+            merge_var_fields_arg_ty: merge.merge_fields_arg_ty.clone(),
+            merge_var_field_head_bounds: merge.merge_field_head_bounds.clone(),
+            merge_var_fields_bounds: merge.merge_fields_bounds.clone(),
+            merge_var_args: merge.merge_args.clone(),
+            merge_var_arg_idents: merge.merge_arg_idents.clone(),
+        }
+    }
 
-        let (
-            FnArg::Typed(PatType {
-                pat: lp, ty: lty, ..
-            }),
-            FnArg::Typed(PatType {
-                pat: rp, ty: rty, ..
-            }),
-        ) = (head, tail)
-        else {
-            return Err("Expected additional comparator arguments to be typed patterns.");
+    /// The first use of a tail argument in the body.
+    fn tail_use(&self) -> Option<Ident> {
+        tail_use(
+            &self.merge_var_body,
+            &self.merge_var_fields_arg,
+            &self.merge_var_fields_arg_ty,
+            &self.merge_var_args,
+        )
+    }
+
+    fn validate_comparator(&mut self, arity: usize) -> Result<(), String> {
+        self.merge_var_arg_idents = comparator_operands(
+            &self.merge_var_args,
+            &self.merge_var_head_arg_ty,
+            &self.merge_var_fields_arg_ty,
+            arity,
+        )?;
+        Ok(())
+    }
+
+    fn validate_heterogeneous(&mut self, rhs: &Ident) -> Result<(), String> {
+        self.merge_var_arg_idents = heterogeneous_operands(&self.merge_var_args, rhs)?;
+        Ok(())
+    }
+}
+
+/// Idents of the head and tail of every further operand of a comparator merge, which come in
+/// pairs typed as the head and tail of the first operand.
+fn comparator_operands(
+    args: &Punctuated<FnArg, Comma>,
+    head_ty: &Ident,
+    tail_ty: &Ident,
+    arity: usize,
+) -> Result<Punctuated<Ident, Comma>, String> {
+    if args.len() != 2 * (arity - 1) {
+        return Err(format!(
+            "Expected exactly {} additional arguments in comparator merge.",
+            2 * (arity - 1)
+        ));
+    }
+    let mut idents = Punctuated::new();
+    for (i, arg) in args.iter().enumerate() {
+        let FnArg::Typed(PatType { pat, ty, .. }) = arg else {
+            return Err("Expected additional comparator arguments to be typed patterns.".into());
         };
-        let (Pat::Ident(li), Pat::Ident(ri)) = (lp.deref(), rp.deref()) else {
-            return Err("Expected ident patterns for additional comparator arguments");
+        let Pat::Ident(ident) = pat.deref() else {
+            return Err("Expected ident patterns for additional comparator arguments".into());
         };
-        self.merge_var_arg_idents.push(li.ident.clone());
-        self.merge_var_arg_idents.push(ri.ident.clone());
-
-        let (Type::Path(TypePath { path: ltpath, .. }), Type::Path(TypePath { path: rtpath, .. })) =
-            (lty.deref(), rty.deref())
-        else {
+        let expected = if i % 2 == 0 { head_ty } else { tail_ty };
+        let Type::Path(TypePath { path, .. }) = ty.deref() else {
             return Err(
-                "Expected head field and tail fields as types for additional comparator arguments",
+                "Expected head field and tail fields as types for additional comparator arguments"
+                    .into(),
             );
         };
-        if !ltpath.is_ident(&self.merge_var_head_arg_ty)
-            || !rtpath.is_ident(&self.merge_var_fields_arg_ty)
-        {
+        if !path.is_ident(expected) {
             return Err(
-                "Expected head field and tail fields as types for additional comparator arguments",
+                "Expected head field and tail fields as types for additional comparator arguments"
+                    .into(),
             );
         }
+        idents.push(ident.ident.clone());
+    }
+    Ok(idents)
+}
+/// Idents of the field of `Rhs` lined up with the head, typed `L2`, and of `other: &Rhs`, which
+/// a merge of a `comparator(with = Rhs)` takes after the head and tail of `Self`.
+fn heterogeneous_operands(
+    args: &Punctuated<FnArg, Comma>,
+    rhs: &Ident,
+) -> Result<Punctuated<Ident, Comma>, String> {
+    let head = match args.first() {
+        Some(FnArg::Typed(PatType { pat, ty, .. })) => match (pat.deref(), ty.deref()) {
+            (Pat::Ident(pat), Type::Path(p)) if p.path.is_ident("L2") => Some(pat.ident.clone()),
+            _ => None,
+        },
+        _ => None,
+    };
+    match (head, rhs_operand(args.iter().nth(1), rhs)) {
+        (Some(head), Some(other)) if args.len() == 2 => Ok([head, other].into_iter().collect()),
+        _ => Err(format!(
+            "Expected the head and tail of `Self` to be followed by the field of `{rhs}` lined up with the head and `other: &{rhs}`, e.g. `(l: L, r: R, l2: L2, other: &{rhs})`."
+        )),
+    }
+}
 
-        Ok(())
+/// The ident of an `other: &Rhs` argument.
+fn rhs_operand(arg: Option<&FnArg>, rhs: &Ident) -> Option<Ident> {
+    match arg? {
+        FnArg::Typed(PatType { pat, ty, .. }) => match (pat.deref(), ty.deref()) {
+            (Pat::Ident(pat), Type::Reference(r))
+                if r.mutability.is_none()
+                    && matches!(r.elem.deref(), Type::Path(p) if p.path.is_ident(rhs)) =>
+            {
+                Some(pat.ident.clone())
+            }
+            _ => None,
+        },
+        _ => None,
     }
 }
 struct Join {
@@ -1494,31 +2416,150 @@ impl Join {
         })
     }
 
-    fn validate_comparator(&mut self) -> Result<(), &str> {
-        self.join_arg_idents.clear();
-        let (Some(fields), None) = (self.join_args.get(0), self.join_args.get(1)) else {
-            return Err("Expected exactly 1 additional argument in comparator join.");
+    fn validate_heterogeneous(&mut self, rhs: &Ident) -> Result<(), String> {
+        let other = rhs_operand(self.join_args.first(), rhs).filter(|_| self.join_args.len() == 1);
+        let Some(other) = other else {
+            return Err(format!(
+                "Expected `join` to take the fields of `Self` and `other: &{rhs}`."
+            ));
         };
+        self.join_arg_idents = [other].into_iter().collect();
+        Ok(())
+    }
 
-        let FnArg::Typed(PatType { pat, ty: lty, .. }) = fields else {
-            return Err("Expected additional comparator argument to be a typed pattern.");
-        };
-        let Pat::Ident(ident) = pat.deref() else {
-            return Err("Expected ident for additional comparator arg");
-        };
-        self.join_arg_idents.push(ident.ident.clone());
+    fn validate_comparator(&mut self, arity: usize) -> Result<(), String> {
+        self.join_arg_idents.clear();
+        if self.join_args.len() != arity - 1 {
+            return Err(if arity == 2 {
+                "Expected exactly 1 additional argument in comparator join.".into()
+            } else {
+                format!(
+                    "Expected exactly {} additional arguments in comparator join.",
+                    arity - 1
+                )
+            });
+        }
+        for arg in self.join_args.iter() {
+            let FnArg::Typed(PatType { pat, ty: lty, .. }) = arg else {
+                return Err(
+                    "Expected additional comparator argument to be a typed pattern.".into(),
+                );
+            };
+            let Pat::Ident(ident) = pat.deref() else {
+                return Err("Expected ident for additional comparator arg".into());
+            };
+            self.join_arg_idents.push(ident.ident.clone());
 
-        let Type::Path(TypePath { path: ltpath, .. }) = lty.deref() else {
-            return Err("Expected the same fields type for additional comparator argument");
-        };
-        if !ltpath.is_ident(&self.join_fields_arg_ty) {
-            return Err("Expected the same fields type for additional comparator argument");
+            let Type::Path(TypePath { path: ltpath, .. }) = lty.deref() else {
+                return Err(
+                    "Expected the same fields type for additional comparator argument".into(),
+                );
+            };
+            if !ltpath.is_ident(&self.join_fields_arg_ty) {
+                return Err(
+                    "Expected the same fields type for additional comparator argument".into(),
+                );
+            }
         }
 
         Ok(())
     }
 }
 
+/// Runs once per enum variant with the variant's header, its fields and the remaining variants.
+struct MergeVariant {
+    merge_variant_body: Block,
+    merge_variant_ret: proc_macro2::TokenStream,
+    merge_variant_fields_ident: Ident,
+    merge_variant_rest_ident: Ident,
+    merge_variant_head_arg: Ident,
+    merge_variant_fields_arg: Ident,
+    merge_variant_rest_arg: Ident,
+    merge_variant_fields_bounds: GenericBounds,
+    merge_variant_rest_bounds: GenericBounds,
+    merge_variant_args: Punctuated<FnArg, Comma>,
+    merge_variant_arg_idents: Punctuated<Ident, Comma>,
+}
+impl MergeVariant {
+    fn parse(f: &TraitItemFn) -> Result<Self, &str> {
+        if f.sig.generics.params.len() != 2 {
+            return Err("Expected `merge_variant` to be generic over the variant's fields and the remaining variants only.");
+        }
+        Ok(Self {
+            merge_variant_body: f.body()?,
+            merge_variant_ret: f.ret(),
+            merge_variant_fields_ident: f.generic_ident(0)?,
+            merge_variant_rest_ident: f.generic_ident(1)?,
+            merge_variant_head_arg: f.arg_ident(0)?,
+            merge_variant_fields_arg: f.arg_ident(1)?,
+            merge_variant_rest_arg: f.arg_ident(2)?,
+            merge_variant_fields_bounds: f.generic_bound(0)?,
+            merge_variant_rest_bounds: f.generic_bound(1)?,
+            merge_variant_args: f.args(3)?,
+            merge_variant_arg_idents: f.arg_idents(3)?,
+        })
+    }
+}
+
+/// An `enter` or `exit` step, run before or after the fields of a nested type are folded.
+struct Hook {
+    hook_body: Block,
+    hook_args: Punctuated<FnArg, Comma>,
+}
+impl Hook {
+    fn parse(f: &TraitItemFn) -> Result<Self, &str> {
+        if f.sig.receiver().is_some() || f.sig.inputs.is_empty() {
+            return Err("Expected the type name as the first argument of `enter` and `exit`.");
+        }
+        if f.sig.asyncness.is_some() || !matches!(f.sig.output, ReturnType::Default) {
+            return Err("`enter` and `exit` must be synchronous and return nothing.");
+        }
+        Ok(Self {
+            hook_body: f.body()?,
+            hook_args: f.args(0)?,
+        })
+    }
+}
+
+/// A `merge_continue` step, combining the `Continue` values of the head and tail of a
+/// `short_circuit` behavior.
+struct MergeContinue {
+    continue_body: Block,
+    continue_ret: ReturnType,
+    continue_args: Punctuated<FnArg, Comma>,
+}
+impl MergeContinue {
+    fn parse(f: &TraitItemFn) -> Result<Self, &str> {
+        if f.sig.receiver().is_some() || f.sig.inputs.len() != 2 {
+            return Err("Expected the `Continue` values of the head and the tail as the only arguments of `merge_continue`.");
+        }
+        if f.sig.asyncness.is_some() {
+            return Err("`merge_continue` must be synchronous.");
+        }
+        Ok(Self {
+            continue_body: f.body()?,
+            continue_ret: f.sig.output.clone(),
+            continue_args: f.args(0)?,
+        })
+    }
+}
+
+/// The generated items for one behavior method, combined by `State::finish`.
+struct Expansion {
+    property_ident: Ident,
+    trait_head: proc_macro2::TokenStream,
+    assoc_trait_items: proc_macro2::TokenStream,
+    trait_fn: proc_macro2::TokenStream,
+    property_impls: proc_macro2::TokenStream,
+    mod_ident: Ident,
+    module_body: proc_macro2::TokenStream,
+    blanket_impl_head: proc_macro2::TokenStream,
+    blanket_where: proc_macro2::TokenStream,
+    assoc_impl_items: proc_macro2::TokenStream,
+    blanket_fn: proc_macro2::TokenStream,
+    impls: proc_macro2::TokenStream,
+}
+
 pub struct State {
     trait_ident: Ident,
     trait_generics: syn::Generics,
@@ -1527,6 +2568,8 @@ pub struct State {
     signature: Option<Signature>,
     mod_ident: Ident,
     fn_ident: Ident,
+    fn_generics: syn::Generics,
+    fn_async: bool,
     fn_args: proc_macro2::TokenStream,
     fn_args_list: Punctuated<FnArg, Comma>,
     fn_arg_idents: Punctuated<Ident, Comma>,
@@ -1534,10 +2577,20 @@ pub struct State {
     vis: Visibility,
     kind: Kind,
     assoc_types: Vec<AssocTypeSpec>,
+    assoc_consts: Vec<AssocConstSpec>,
     nothing: Option<Nothing>,
     merge_field: Option<MergeField>,
     merge_var: Option<MergeVar>,
+    merge_variant: Option<MergeVariant>,
     join: Option<Join>,
+    enter: Option<Hook>,
+    exit: Option<Hook>,
+    merge_continue: Option<MergeContinue>,
+    fallible: bool,
+    short_circuit: bool,
+    field_path: Option<Ident>,
+    comparator_arity: usize,
+    comparator_with: Option<With>,
 }
 
 impl State {
@@ -1545,175 +2598,486 @@ impl State {
         let input = parse_macro_input!(item as syn::Item);
         match input {
             syn::Item::Trait(x) => {
-                let Attributes {
-                    property,
-                    comparator,
-                    types_only,
-                    signature,
-                } = match syn::parse::<Attributes>(attr) {
+                let attrs = match syn::parse::<Attributes>(attr) {
                     Ok(attrs) => attrs,
                     Err(e) => return e.into_compile_error().into(),
                 };
 
-                match State::process(x, property, comparator, types_only, signature) {
-                    Ok(tt) => tt,
-                    Err(tt) => tt,
+                match State::process(x, attrs) {
+                    Ok(tt) => tt,
+                    Err(tt) => tt,
+                }
+            }
+            item => syn::Error::new_spanned(
+                item,
+                "This macro can only be applied to trait definitions.",
+            )
+            .to_compile_error()
+            .into(),
+        }
+    }
+
+    fn new(tr: &ItemTrait, property_ident: Ident, signature: Option<Signature>) -> Self {
+        State {
+            trait_ident: tr.ident.clone(),
+            trait_generics: tr.generics.clone(),
+            trait_supertraits: tr.supertraits.clone(),
+            property_ident,
+            signature,
+            mod_ident: format_ident!("__inception_{}", Identifier::modularize(&tr.ident)),
+            fn_ident: format_ident!("unknown"),
+            fn_generics: Default::default(),
+            fn_async: false,
+            fn_ret: ReturnType::Default,
+            fn_args: Default::default(),
+            fn_args_list: Default::default(),
+            fn_arg_idents: Default::default(),
+            vis: tr.vis.clone(),
+            kind: Kind::Ty,
+            assoc_types: vec![],
+            assoc_consts: vec![],
+            nothing: None,
+            merge_field: None,
+            merge_var: None,
+            merge_variant: None,
+            join: None,
+            enter: None,
+            exit: None,
+            merge_continue: None,
+            fallible: false,
+            short_circuit: false,
+            field_path: None,
+            comparator_arity: 2,
+            comparator_with: None,
+        }
+    }
+
+    fn process(tr: ItemTrait, attrs: Attributes) -> Result<TokenStream, TokenStream> {
+        let Attributes {
+            property: property_ident,
+            comparator,
+            comparator_arity,
+            comparator_with,
+            types_only: is_types_only,
+            fallible: is_fallible,
+            short_circuit: is_short_circuit,
+            field_path,
+            signature,
+        } = attrs;
+        let comparator_arity = comparator.then_some(comparator_arity);
+        let is_comparator = comparator_arity.is_some();
+        let mut assoc_types = vec![];
+        let mut assoc_consts = vec![];
+        let fns = tr
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                TraitItem::Fn(f) => Some(f),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for item in tr.items.iter() {
+            match item {
+                TraitItem::Fn(f) if is_types_only => {
+                    return Err(syn::Error::new_spanned(
+                        f,
+                        "`types` mode does not support behavior methods.",
+                    )
+                    .into_compile_error()
+                    .into())
+                }
+                TraitItem::Macro(m) => {
+                    return Err(syn::Error::new_spanned(m, "Unsupported")
+                        .into_compile_error()
+                        .into())
+                }
+                TraitItem::Type(t) => {
+                    let mut item = t.clone();
+                    let induce = match extract_induce_attr(&mut item.attrs) {
+                        Ok(spec) => spec,
+                        Err(e) => return Err(e.into_compile_error().into()),
+                    };
+                    assoc_types.push(AssocTypeSpec { item, induce });
+                }
+                TraitItem::Const(c) => {
+                    let mut item = c.clone();
+                    let induce = match extract_induce_const_attr(&mut item.attrs) {
+                        Ok(spec) => spec,
+                        Err(e) => return Err(e.into_compile_error().into()),
+                    };
+                    if induce.is_none() && item.default.is_none() {
+                        return Err(syn::Error::new_spanned(
+                            c,
+                            "Associated consts need a default value or `#[induce_const(...)]`.",
+                        )
+                        .into_compile_error()
+                        .into());
+                    }
+                    if induce.is_some() && !tr.generics.params.is_empty() {
+                        return Err(syn::Error::new_spanned(
+                            c,
+                            "`#[induce_const(...)]` is not supported on generic traits.",
+                        )
+                        .into_compile_error()
+                        .into());
+                    }
+                    assoc_consts.push(AssocConstSpec { item, induce });
+                }
+                _ => {}
+            }
+        }
+
+        if is_fallible && is_short_circuit {
+            return Err(syn::Error::new_spanned(
+                &tr.ident,
+                "`fallible` and `short_circuit` cannot be combined.",
+            )
+            .into_compile_error()
+            .into());
+        }
+        if is_fallible
+            && (is_types_only || is_comparator || signature.is_some() || !assoc_types.is_empty())
+        {
+            return Err(syn::Error::new_spanned(
+                &tr.ident,
+                "`fallible` is not compatible with `types`, `comparator`, `signature(...)` or associated types.",
+            )
+            .into_compile_error()
+            .into());
+        }
+        if let Some(With { rhs, .. }) = &comparator_with {
+            if is_types_only
+                || is_short_circuit
+                || field_path.is_some()
+                || signature.is_some()
+                || !assoc_types.is_empty()
+                || !assoc_consts.is_empty()
+            {
+                return Err(syn::Error::new_spanned(
+                    rhs,
+                    "`comparator(with = ...)` is not compatible with `types`, `short_circuit`, `field_path = ...`, `signature(...)` or associated items.",
+                )
+                .into_compile_error()
+                .into());
+            }
+            let generic_ok = tr.generics.params.len() == 1
+                && matches!(tr.generics.params.first(), Some(GenericParam::Type(t)) if t.ident == *rhs);
+            if !generic_ok || tr.generics.where_clause.is_some() {
+                let msg = format!(
+                    "`comparator(with = {rhs})` expects the trait to be generic over `{rhs}` alone."
+                );
+                return Err(syn::Error::new_spanned(rhs, msg)
+                    .into_compile_error()
+                    .into());
+            }
+        }
+        if is_types_only {
+            let mut st = State::new(&tr, property_ident, signature);
+            st.assoc_types = assoc_types;
+            st.assoc_consts = assoc_consts;
+            return Ok(st.finish_types_only(is_comparator));
+        }
+
+        // Steps belong to the method named by their prefix (`print_merge`), or to the only method
+        // when unprefixed.
+        let step_of = |f: &TraitItemFn| -> Option<(Option<String>, String)> {
+            let name = f.sig.ident.to_string();
+            if f.is_reserved() {
+                return Some((None, name));
+            }
+            <TraitItemFn as ParseFn>::RESERVED.iter().find_map(|step| {
+                let method = name.strip_suffix(step)?.strip_suffix('_')?;
+                fns.iter()
+                    .any(|g| g.sig.ident == method)
+                    .then(|| (Some(method.to_string()), step.to_string()))
+            })
+        };
+        let methods = fns
+            .iter()
+            .filter(|f| step_of(f).is_none())
+            .collect::<Vec<_>>();
+
+        if methods.is_empty() {
+            let msg = &format!(
+                    "Expected at least 1 behavior method besides the steps (\"{NOTHING_FN_IDENT}\", \"{MERGE_FN_IDENT}\", \"{MERGE_VAR_FN_IDENT}\", \"{JOIN_FN_IDENT}\", ...), each of which may be prefixed with the method it belongs to.");
+            return Err(syn::Error::new_spanned(tr, msg).into_compile_error().into());
+        }
+        if methods.len() > 1 && (!assoc_types.is_empty() || signature.is_some()) {
+            return Err(syn::Error::new_spanned(
+                methods[1],
+                "Several behavior methods are not supported together with associated types or `signature(...)`.",
+            )
+            .into_compile_error()
+            .into());
+        }
+
+        if let Some(With { rhs, .. }) = &comparator_with {
+            let behavior = methods[0];
+            let other = rhs_operand(behavior.sig.inputs.iter().nth(1), rhs);
+            if methods.len() > 1
+                || other.is_none()
+                || !matches!(behavior.kind(), Kind::Ref)
+                || behavior.sig.inputs.len() != 2
+                || !behavior.sig.generics.params.is_empty()
+                || behavior.sig.asyncness.is_some()
+            {
+                let msg = format!(
+                    "`comparator(with = {rhs})` expects a single behavior method, a synchronous, non-generic `fn(&self, other: &{rhs})`."
+                );
+                return Err(syn::Error::new_spanned(&behavior.sig, msg)
+                    .into_compile_error()
+                    .into());
+            }
+        }
+
+        let mut states = vec![];
+        for method in methods.iter() {
+            let mut st = State::new(&tr, property_ident.clone(), signature.clone());
+            if methods.len() > 1 {
+                st.mod_ident = format_ident!(
+                    "__inception_{}_{}",
+                    Identifier::modularize(&tr.ident),
+                    method.sig.ident
+                );
+            }
+            st.assoc_types = assoc_types.clone();
+            st.assoc_consts = assoc_consts.clone();
+            st.fallible = is_fallible;
+            st.short_circuit = is_short_circuit;
+            st.field_path = field_path.clone();
+            st.comparator_arity = comparator_arity.unwrap_or(2);
+            st.comparator_with = comparator_with.clone();
+            st.set_method(method);
+            states.push(st);
+        }
+        for f in fns.iter() {
+            let Some((prefix, step)) = step_of(f) else {
+                continue;
+            };
+            let st = match prefix {
+                Some(prefix) => states.iter_mut().find(|st| st.fn_ident == prefix),
+                None if states.len() == 1 => states.first_mut(),
+                None => {
+                    let msg = format!(
+                        "Prefix \"{step}\" with the method it belongs to, e.g. \"{}_{step}\", when a trait has several behavior methods.",
+                        methods[0].sig.ident
+                    );
+                    return Err(syn::Error::new_spanned(f, msg).into_compile_error().into());
+                }
+            };
+            if let Some(st) = st {
+                st.set_step(f, &step, is_comparator)?;
+            }
+        }
+
+        Ok(State::finish(states, is_comparator))
+    }
+
+    fn set_method(&mut self, f: &TraitItemFn) {
+        let kind = f.kind();
+        let is_ty = matches!(&kind, Kind::Ty);
+        self.kind = kind;
+        self.fn_ident = f.sig.ident.clone();
+        self.fn_generics = f.sig.generics.clone();
+        self.fn_async = f.sig.asyncness.is_some();
+        self.fn_ret = f.sig.output.clone();
+        let skip = if is_ty { 0 } else { 1 };
+        let args = f.sig.inputs.iter().skip(skip).collect::<Vec<_>>();
+        self.fn_args = {
+            if !args.is_empty() {
+                if is_ty {
+                    quote! { #(#args),* }
+                } else {
+                    quote! { , #(#args),* }
+                }
+            } else {
+                quote! {}
+            }
+        };
+        self.fn_arg_idents = {
+            args.iter()
+                .filter_map(|arg| match arg {
+                    FnArg::Receiver(_) => None,
+                    FnArg::Typed(PatType { pat, .. }) => match &**pat {
+                        Pat::Ident(p) => Some(p.ident.clone()),
+                        _ => None,
+                    },
+                })
+                .collect()
+        };
+        self.fn_args_list = args.into_iter().cloned().collect();
+    }
+
+    fn set_step(
+        &mut self,
+        f: &TraitItemFn,
+        step: &str,
+        is_comparator: bool,
+    ) -> Result<(), TokenStream> {
+        let err =
+            |s: &str| -> TokenStream { syn::Error::new_spanned(f, s).into_compile_error().into() };
+        let arity = self.comparator_arity;
+        match Step::parse(f, step) {
+            Ok(Step::Base(nothing)) => {
+                self.nothing = Some(nothing);
+            }
+            Ok(Step::Merge(mut merge)) => {
+                if let Some(with) = &self.comparator_with {
+                    merge
+                        .validate_heterogeneous(&with.rhs)
+                        .map_err(|e| err(&e))?;
+                } else if is_comparator {
+                    merge.validate_comparator(arity).map_err(|e| err(&e))?;
                 }
+                self.merge_field = Some(merge);
             }
-            item => syn::Error::new_spanned(
-                item,
-                "This macro can only be applied to trait definitions.",
-            )
-            .to_compile_error()
-            .into(),
+            Ok(Step::Enum(mut merge_var)) => {
+                if let Some(with) = &self.comparator_with {
+                    merge_var
+                        .validate_heterogeneous(&with.rhs)
+                        .map_err(|e| err(&e))?;
+                } else if is_comparator {
+                    merge_var.validate_comparator(arity).map_err(|e| err(&e))?;
+                }
+                self.merge_var = Some(merge_var);
+            }
+            Ok(Step::Variant(_))
+                if is_comparator
+                    || self.fallible
+                    || self.fn_async
+                    || self.signature.is_some()
+                    || !self.assoc_types.is_empty() =>
+            {
+                return Err(err(
+                    "`merge_variant` is not compatible with `comparator`, `fallible`, `async`, `signature(...)` or associated types.",
+                ));
+            }
+            Ok(Step::Variant(merge_variant)) => {
+                self.merge_variant = Some(merge_variant);
+            }
+            Ok(Step::Join(mut join)) => {
+                if let Some(with) = &self.comparator_with {
+                    join.validate_heterogeneous(&with.rhs)
+                        .map_err(|e| err(&e))?;
+                } else if is_comparator {
+                    join.validate_comparator(arity).map_err(|e| err(&e))?;
+                }
+                self.join = Some(join);
+            }
+            Ok(Step::Enter(_) | Step::Exit(_))
+                if is_comparator || self.signature.is_some() || !self.assoc_types.is_empty() =>
+            {
+                return Err(err(
+                    "`enter` and `exit` are not compatible with `comparator`, `signature(...)` or associated types.",
+                ));
+            }
+            Ok(Step::Enter(enter)) => {
+                self.enter = Some(enter);
+            }
+            Ok(Step::Exit(exit)) => {
+                self.exit = Some(exit);
+            }
+            Ok(Step::Continue(_)) if !self.short_circuit => {
+                return Err(err(
+                    "`merge_continue` only applies to `short_circuit` behaviors.",
+                ));
+            }
+            Ok(Step::Continue(merge_continue)) => {
+                self.merge_continue = Some(merge_continue);
+            }
+            Err(e) => return Err(err(e)),
         }
+        Ok(())
     }
 
-    fn process(
-        tr: ItemTrait,
-        property_ident: Ident,
-        is_comparator: bool,
-        is_types_only: bool,
-        signature: Option<Signature>,
-    ) -> Result<TokenStream, TokenStream> {
-        let mut st = State {
-            trait_ident: tr.ident.clone(),
-            trait_generics: tr.generics.clone(),
-            trait_supertraits: tr.supertraits.clone(),
-            property_ident,
-            signature,
-            mod_ident: format_ident!("__inception_{}", Identifier::modularize(&tr.ident)),
-            fn_ident: format_ident!("unknown"),
-            fn_ret: ReturnType::Default,
-            fn_args: Default::default(),
-            fn_args_list: Default::default(),
-            fn_arg_idents: Default::default(),
-            vis: tr.vis.clone(),
-            kind: Kind::Ty,
-            assoc_types: vec![],
-            nothing: None,
-            merge_field: None,
-            merge_var: None,
-            join: None,
+    fn finish(methods: Vec<Self>, is_comparator: bool) -> TokenStream {
+        let Some(st) = methods.first() else {
+            return quote! {}.into();
+        };
+        let assoc_const_items = st.assoc_consts.iter().map(|c| &c.item).collect::<Vec<_>>();
+        let InducedConsts {
+            helpers: const_helpers,
+            impl_items: const_impl_items,
+            blanket_where: const_blanket_where,
+        } = induced_consts(
+            &st.assoc_consts,
+            &st.trait_ident,
+            &st.property_ident,
+            &st.mod_ident,
+        );
+        let assoc_const_items = quote! { #(#assoc_const_items)* };
+        let expansions = match methods
+            .into_iter()
+            .map(|st| st.expand(is_comparator))
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(expansions) => expansions,
+            Err(e) => return e,
         };
+        let Some(first) = expansions.first() else {
+            return quote! {}.into();
+        };
+        let Expansion {
+            property_ident,
+            trait_head,
+            assoc_trait_items,
+            property_impls,
+            blanket_impl_head,
+            assoc_impl_items,
+            ..
+        } = first;
+        let trait_fns = expansions.iter().map(|e| &e.trait_fn);
+        let modules = expansions.iter().enumerate().map(|(i, e)| {
+            let Expansion {
+                mod_ident,
+                module_body,
+                ..
+            } = e;
+            // The property is shared by every method, so only the first module implements it along
+            // with the induced consts.
+            let property_impls = (i == 0).then_some(property_impls);
+            let const_helpers = if i == 0 { &const_helpers[..] } else { &[] };
+            quote! {
+                mod #mod_ident {
+                    use inception::{Wrapper, TruthValue, IsPrimitive, meta::Metadata, True, False};
+                    use super::*;
 
-        let mut is_fn_defined: bool = false;
-        for item in tr.items.iter() {
-            match item {
-                TraitItem::Fn(f) => match (f.is_reserved(), f.kind(), is_fn_defined) {
-                    _ if is_types_only => {
-                        return Err(syn::Error::new_spanned(
-                            f,
-                            "`types` mode does not support behavior methods.",
-                        )
-                        .into_compile_error()
-                        .into())
-                    }
-                    (false, kind, false) => {
-                        is_fn_defined = true;
-                        let is_ty = matches!(&kind, Kind::Ty);
-                        st.kind = kind;
-                        st.fn_ident = f.sig.ident.clone();
-                        st.fn_ret = f.sig.output.clone();
-                        let skip = if is_ty { 0 } else { 1 };
-                        let args = f.sig.inputs.iter().skip(skip).collect::<Vec<_>>();
-                        st.fn_args = {
-                            if !args.is_empty() {
-                                if is_ty {
-                                    quote! { #(#args),* }
-                                } else {
-                                    quote! { , #(#args),* }
-                                }
-                            } else {
-                                quote! {}
-                            }
-                        };
-                        st.fn_arg_idents = {
-                            args.iter()
-                                .filter_map(|arg| match arg {
-                                    FnArg::Receiver(_) => None,
-                                    FnArg::Typed(PatType { pat, .. }) => match &**pat {
-                                        Pat::Ident(p) => Some(p.ident.clone()),
-                                        _ => None,
-                                    },
-                                })
-                                .collect()
-                        };
-                        st.fn_args_list = args.into_iter().cloned().collect();
-                    }
-                    (false, _, true) => {
-                        return Err(
-                            syn::Error::new_spanned(f, "Unexpected function definition.")
-                                .into_compile_error()
-                                .into(),
-                        )
-                    }
-                    (true, _, _) => match Step::parse(f) {
-                        Ok(Step::Base(nothing)) => {
-                            st.nothing = Some(nothing);
-                        }
-                        Ok(Step::Merge(mut merge)) => {
-                            if is_comparator {
-                                merge.validate_comparator().map_err(|s| {
-                                    syn::Error::new_spanned(f, s).into_compile_error()
-                                })?;
-                            }
-                            st.merge_field = Some(merge);
-                        }
-                        Ok(Step::Enum(mut merge_var)) => {
-                            if is_comparator {
-                                merge_var.validate_comparator().map_err(|s| {
-                                    syn::Error::new_spanned(f, s).into_compile_error()
-                                })?;
-                            }
-                            st.merge_var = Some(merge_var);
-                        }
-                        Ok(Step::Join(mut join)) => {
-                            if is_comparator {
-                                join.validate_comparator().map_err(|s| {
-                                    syn::Error::new_spanned(f, s).into_compile_error()
-                                })?;
-                            }
-                            st.join = Some(join);
-                        }
-                        Err(e) => {
-                            return Err(syn::Error::new_spanned(f, e).into_compile_error().into());
-                        }
-                    },
-                },
-
-                TraitItem::Macro(m) => {
-                    return Err(syn::Error::new_spanned(m, "Unsupported")
-                        .into_compile_error()
-                        .into())
+                    #property_impls
+                    #(#const_helpers)*
+                    #module_body
                 }
+            }
+        });
+        let blanket_where = expansions.iter().map(|e| &e.blanket_where);
+        let blanket_fns = expansions.iter().map(|e| &e.blanket_fn);
+        let impls = expansions.iter().map(|e| &e.impls);
 
-                TraitItem::Type(t) => {
-                    let mut item = t.clone();
-                    let induce = match extract_induce_attr(&mut item.attrs) {
-                        Ok(spec) => spec,
-                        Err(e) => return Err(e.into_compile_error().into()),
-                    };
-                    st.assoc_types.push(AssocTypeSpec { item, induce });
-                }
-                _ => {}
+        quote! {
+            pub struct #property_ident;
+            #trait_head {
+                #assoc_trait_items
+                #assoc_const_items
+                #(#trait_fns)*
             }
-        }
 
-        if !is_types_only && !is_fn_defined {
-            let msg = &format!(
-                    "Expected 1 function besides \"{NOTHING_FN_IDENT}\" \"{MERGE_FN_IDENT}\" \"{MERGE_VAR_FN_IDENT}\" or \"{JOIN_FN_IDENT}\"");
-            return Err(syn::Error::new_spanned(tr, msg).into_compile_error().into());
-        }
+            #(#modules)*
 
-        Ok(st.finish(is_comparator, is_types_only))
-    }
+            #blanket_impl_head
+            where
+                #(#const_blanket_where)*
+                #(#blanket_where)*
+            {
+                #assoc_impl_items
+                #(#const_impl_items)*
+                #(#blanket_fns)*
+            }
 
-    fn finish(self, is_comparator: bool, is_types_only: bool) -> TokenStream {
-        if is_types_only {
-            return self.finish_types_only(is_comparator);
+            #(#impls)*
         }
+        .into()
+    }
 
+    fn expand(self, is_comparator: bool) -> Result<Expansion, TokenStream> {
         let State {
             mod_ident,
             trait_ident,
@@ -1724,6 +3088,8 @@ impl State {
             vis,
             kind,
             fn_ident,
+            fn_generics,
+            fn_async,
             fn_ret,
             fn_args,
             fn_args_list,
@@ -1731,8 +3097,17 @@ impl State {
             nothing,
             merge_field,
             merge_var,
+            merge_variant,
             join,
+            enter,
+            exit,
+            merge_continue,
             assoc_types,
+            fallible,
+            short_circuit,
+            field_path,
+            comparator_arity,
+            comparator_with,
             ..
         } = self;
 
@@ -1742,9 +3117,9 @@ impl State {
             .any(|p| !matches!(p, GenericParam::Type(_)));
         if has_non_type {
             let msg = "Only type generics are currently supported for #[inception] traits.";
-            return syn::Error::new_spanned(trait_ident.clone(), msg)
+            return Err(syn::Error::new_spanned(trait_ident.clone(), msg)
                 .into_compile_error()
-                .into();
+                .into());
         }
         let type_params = trait_generics
             .params
@@ -1768,9 +3143,9 @@ impl State {
             .collect::<Vec<_>>();
         if type_params.len() > 1 {
             let msg = "Only a single type generic is currently supported for #[inception] traits.";
-            return syn::Error::new_spanned(trait_ident.clone(), msg)
+            return Err(syn::Error::new_spanned(trait_ident.clone(), msg)
                 .into_compile_error()
-                .into();
+                .into());
         }
         let assoc_type_idents = assoc_types
             .iter()
@@ -1809,18 +3184,18 @@ impl State {
                             "Signature input `{}` is ambiguous; it matches both a type generic and an associated type.",
                             signature.input
                         );
-                    return syn::Error::new_spanned(trait_ident.clone(), msg)
+                    return Err(syn::Error::new_spanned(trait_ident.clone(), msg)
                         .into_compile_error()
-                        .into();
+                        .into());
                 }
                 (false, false) => {
                     let msg = format!(
                             "Signature input `{}` must name either a trait type generic or an associated type.",
                             signature.input
                         );
-                    return syn::Error::new_spanned(trait_ident.clone(), msg)
+                    return Err(syn::Error::new_spanned(trait_ident.clone(), msg)
                         .into_compile_error()
-                        .into();
+                        .into());
                 }
             };
 
@@ -1832,27 +3207,27 @@ impl State {
                 }
                 (true, false) => {
                     let msg = "Trait-generic output signatures are not yet supported; use an associated output type.";
-                    return syn::Error::new_spanned(trait_ident.clone(), msg)
+                    return Err(syn::Error::new_spanned(trait_ident.clone(), msg)
                         .into_compile_error()
-                        .into();
+                        .into());
                 }
                 (true, true) => {
                     let msg = format!(
                             "Signature output `{}` is ambiguous; it matches both a type generic and an associated type.",
                             signature.output
                         );
-                    return syn::Error::new_spanned(trait_ident.clone(), msg)
+                    return Err(syn::Error::new_spanned(trait_ident.clone(), msg)
                         .into_compile_error()
-                        .into();
+                        .into());
                 }
                 (false, false) => {
                     let msg = format!(
                         "Signature output `{}` must name an associated type.",
                         signature.output
                     );
-                    return syn::Error::new_spanned(trait_ident.clone(), msg)
+                    return Err(syn::Error::new_spanned(trait_ident.clone(), msg)
                         .into_compile_error()
-                        .into();
+                        .into());
                 }
             }
 
@@ -1860,38 +3235,21 @@ impl State {
         } else {
             if !type_params.is_empty() && input_assoc_ident.is_some() {
                 let msg = "Use either a trait input generic or an associated input type, not both.";
-                return syn::Error::new_spanned(trait_ident.clone(), msg)
+                return Err(syn::Error::new_spanned(trait_ident.clone(), msg)
                     .into_compile_error()
-                    .into();
+                    .into());
             }
             let flow_input_from_assoc = type_params.is_empty() && input_assoc_ident.is_some();
+            // The `Rhs` of a `comparator(with = Rhs)` is compared against rather than flowed through.
             let flow_input_ident = if flow_input_from_assoc {
                 input_assoc_ident.clone()
+            } else if comparator_with.is_some() {
+                None
             } else {
                 type_params.first().cloned()
             };
             (flow_input_ident, None, flow_input_from_assoc)
         };
-        if !matches!(kind, Kind::Ty)
-            && assoc_types
-                .iter()
-                .any(|t| t.induce.is_some() && !t.item.generics.params.is_empty())
-        {
-            let msg = "GAT induction is currently only supported for type-style #[inception] traits (no self receiver).";
-            return syn::Error::new_spanned(trait_ident.clone(), msg)
-                .into_compile_error()
-                .into();
-        }
-        if assoc_types
-            .iter()
-            .filter_map(|t| t.induce.as_ref())
-            .any(InduceSpec::has_where_bounds)
-        {
-            let msg = "Per-phase `where { ... }` bounds in `#[induce(...)]` are currently supported only for `#[inception(..., types)]` traits.";
-            return syn::Error::new_spanned(trait_ident.clone(), msg)
-                .into_compile_error()
-                .into();
-        }
         let Some(Nothing {
             nothing_body,
             nothing_ret,
@@ -1900,9 +3258,51 @@ impl State {
         }) = nothing
         else {
             let msg = format!("Expected definition for \"{NOTHING_FN_IDENT}\"");
-            return syn::Error::new_spanned(trait_ident, msg)
+            return Err(syn::Error::new_spanned(trait_ident, msg)
                 .into_compile_error()
-                .into();
+                .into());
+        };
+        // `short_circuit` steps only decide on their head field, as the generated impls visit the
+        // tail themselves.
+        let tail_use = merge_field
+            .iter()
+            .filter_map(|m| m.tail_use())
+            .chain(merge_var.iter().filter_map(|m| m.tail_use()))
+            .next();
+        if let Some(r) = tail_use.filter(|_| short_circuit) {
+            let msg = format!(
+                "`{fn_ident}` is `short_circuit`, so its steps must not use the tail `{r}`, which is visited by the generated impls."
+            );
+            return Err(syn::Error::new_spanned(r, msg).into_compile_error().into());
+        }
+        // Without a `merge_variant_field`, the fields of the active variant go through `merge`.
+        let merge_var_default = merge_var.is_none();
+        let default_allowed = !matches!(kind, Kind::Ty)
+            && flow_input_ident.is_none()
+            && flow_output_ident.is_none()
+            && assoc_types.is_empty();
+        let merge_var = match (merge_var, &merge_field) {
+            (None, Some(_)) if is_comparator => {
+                let msg = format!(
+                    "Comparators must define \"{MERGE_VAR_FN_IDENT}\", as only they know how to compare the fields of differing variants."
+                );
+                return Err(syn::Error::new_spanned(trait_ident, msg)
+                    .into_compile_error()
+                    .into());
+            }
+            (None, Some(merge)) if default_allowed => {
+                // Fields of other variants are skipped by handing on the result of the tail.
+                if merge.merge_ret.to_string() != nothing_ret.to_string() {
+                    let msg = format!(
+                        "\"{MERGE_VAR_FN_IDENT}\" can only be omitted when \"{MERGE_FN_IDENT}\" returns the same type as \"{NOTHING_FN_IDENT}\", as fields of other variants then just hand on the result of the tail."
+                    );
+                    return Err(syn::Error::new_spanned(&merge.merge_ret, msg)
+                        .into_compile_error()
+                        .into());
+                }
+                Some(MergeVar::from_merge(merge))
+            }
+            (merge_var, _) => merge_var,
         };
         let Some(MergeField {
             merge_body,
@@ -1920,9 +3320,9 @@ impl State {
         }) = merge_field
         else {
             let msg = format!("Expected definition for \"{MERGE_FN_IDENT}\"");
-            return syn::Error::new_spanned(trait_ident, msg)
+            return Err(syn::Error::new_spanned(trait_ident, msg)
                 .into_compile_error()
-                .into();
+                .into());
         };
         let Some(MergeVar {
             merge_var_body,
@@ -1939,10 +3339,12 @@ impl State {
             ..
         }) = merge_var
         else {
-            let msg = format!("Expected definition for \"{MERGE_VAR_FN_IDENT}\"");
-            return syn::Error::new_spanned(trait_ident, msg)
+            let msg = format!(
+                "Expected definition for \"{MERGE_VAR_FN_IDENT}\", which can only be omitted by behaviors taking `self` outside of `signature(...)` and associated types."
+            );
+            return Err(syn::Error::new_spanned(trait_ident, msg)
                 .into_compile_error()
-                .into();
+                .into());
         };
         let Some(Join {
             join_body,
@@ -1956,9 +3358,243 @@ impl State {
         }) = join
         else {
             let msg = format!("Expected definition for \"{JOIN_FN_IDENT}\"");
-            return syn::Error::new_spanned(trait_ident, msg)
+            return Err(syn::Error::new_spanned(trait_ident, msg)
                 .into_compile_error()
-                .into();
+                .into());
+        };
+
+        for hook in [&enter, &exit].into_iter().flatten() {
+            if hook.hook_args.len() != join_arg_idents.len() + 1 {
+                let msg = format!(
+                    "Expected `enter` and `exit` to take the type name followed by the {} additional argument(s) of \"{JOIN_FN_IDENT}\".",
+                    join_arg_idents.len()
+                );
+                return Err(syn::Error::new_spanned(&hook.hook_args, msg)
+                    .into_compile_error()
+                    .into());
+            }
+        }
+
+        let method_type_idents = fn_generics
+            .params
+            .iter()
+            .filter_map(|p| match p {
+                GenericParam::Type(t) => Some(t.ident.clone()),
+                GenericParam::Const(c) => Some(c.ident.clone()),
+                GenericParam::Lifetime(_) => None,
+            })
+            .collect::<Vec<_>>();
+        let mut merge_extra_generics = merge_extra_generics;
+        let mut merge_var_extra_generics = merge_var_extra_generics;
+        merge_extra_generics.retain(|g| !method_type_idents.contains(g));
+        merge_var_extra_generics.retain(|g| !method_type_idents.contains(g));
+        if let Some(clash) = method_type_idents.iter().find(|g| {
+            [
+                "T", "S", "F", "L", "P", "In", "Out", "Extra", "IDX", "VAR_IDX",
+            ]
+            .iter()
+            .any(|r| *g == r)
+                || **g == merge_head_ident
+                || **g == merge_fields_ident
+                || **g == merge_var_head_ident
+                || **g == merge_var_fields_ident
+                || **g == join_fields_ident
+        }) {
+            let msg = format!(
+                "Generic parameter `{clash}` on `{fn_ident}` collides with a name used by the generated implementations."
+            );
+            return Err(syn::Error::new_spanned(clash, msg)
+                .into_compile_error()
+                .into());
+        }
+        if let ReturnType::Type(_, ty) = &fn_ret {
+            let mut bad = None;
+            walk_type_paths(ty, &mut |tp: &TypePath| {
+                if bad.is_none() {
+                    bad = tp
+                        .path
+                        .segments
+                        .first()
+                        .filter(|s| method_type_idents.contains(&s.ident))
+                        .map(|s| s.ident.clone());
+                }
+            });
+            if let Some(id) = bad {
+                let msg = format!(
+                    "The return type of `{fn_ident}` cannot depend on the method generic `{id}`."
+                );
+                return Err(syn::Error::new_spanned(id, msg).into_compile_error().into());
+            }
+        }
+        let fallible_ok = match fallible.then(|| fallible_ok_ty(&fn_ret)) {
+            Some(None) => {
+                let msg = format!(
+                    "`{fn_ident}` is `fallible` and so must return `Result<_, FieldError<_>>`, spelled out rather than through an alias."
+                );
+                let err = match &fn_ret {
+                    ReturnType::Type(_, ty) => syn::Error::new_spanned(ty, msg),
+                    ReturnType::Default => syn::Error::new_spanned(&fn_ident, msg),
+                };
+                return Err(err.into_compile_error().into());
+            }
+            ok => ok.flatten(),
+        };
+        if short_circuit
+            && !(matches!(kind, Kind::Ref) || matches!(kind, Kind::Ty) && !is_comparator)
+        {
+            let msg = format!(
+                "`{fn_ident}` is `short_circuit` and so must take `&self`, or no receiver outside of comparators."
+            );
+            return Err(syn::Error::new_spanned(&fn_ident, msg)
+                .into_compile_error()
+                .into());
+        }
+        // A `Continue` value other than `()` is only kept by combining that of the head with the
+        // tail's.
+        if short_circuit && merge_continue.is_none() {
+            if let Some(ty) = continue_ty(&fn_ret).filter(|ty| !is_unit(ty)) {
+                let msg = format!(
+                    "`{fn_ident}` continues with `{}`, so it needs a \"{MERGE_CONTINUE_FN_IDENT}\" step to combine that of the head with the tail's.",
+                    quote!(#ty)
+                );
+                return Err(syn::Error::new_spanned(ty, msg).into_compile_error().into());
+            }
+        }
+        // The `field_path = ...` argument is kept pointing at the field being visited.
+        let path_arg = field_path
+            .as_ref()
+            .and_then(|p| named_arg(&fn_args_list, p));
+        let join_path_arg = field_path
+            .as_ref()
+            .and_then(|p| named_arg(&join_extra_args, p));
+        if let (Some(path), None) = (&field_path, &path_arg) {
+            let msg = format!("`{fn_ident}` has no argument `{path}` to keep as its `field_path`.");
+            return Err(syn::Error::new_spanned(path, msg)
+                .into_compile_error()
+                .into());
+        }
+        if path_arg.is_some() && (is_comparator || flow_input_ident.is_some()) {
+            let msg = format!(
+                "`{fn_ident}` keeps a `field_path`, which is not supported together with `comparator` or `signature(...)`."
+            );
+            return Err(syn::Error::new_spanned(&fn_ident, msg)
+                .into_compile_error()
+                .into());
+        }
+        if merge_variant.is_some() && (path_arg.is_some() || short_circuit) {
+            let msg = format!(
+                "`{fn_ident}` keeps a `field_path` or is `short_circuit`, which is not supported together with \"{MERGE_VARIANT_FN_IDENT}\"."
+            );
+            return Err(syn::Error::new_spanned(&fn_ident, msg)
+                .into_compile_error()
+                .into());
+        }
+        if path_arg.is_some() && join_path_arg.is_none() {
+            let msg = format!(
+                "Expected \"{JOIN_FN_IDENT}\" to take the `field_path` argument of `{fn_ident}`."
+            );
+            return Err(syn::Error::new_spanned(&fn_ident, msg)
+                .into_compile_error()
+                .into());
+        }
+        if fn_async && (flow_input_ident.is_some() || !assoc_types.is_empty()) {
+            let msg = format!(
+                "`async fn {fn_ident}` is not supported together with `signature(...)` or associated types."
+            );
+            return Err(syn::Error::new_spanned(&fn_ident, msg)
+                .into_compile_error()
+                .into());
+        }
+        // A behavior returning `Self::X<'_>` borrows its value from `self`, and the `#[induce]`
+        // formulas of `X` give the type returned by each of its steps.
+        let gat_ret = match &fn_ret {
+            ReturnType::Type(_, ty) => {
+                self_gat_ret(ty).and_then(|id| assoc_types.iter().find(|t| t.item.ident == *id))
+            }
+            ReturnType::Default => None,
+        };
+        let gat_ret = match gat_ret {
+            Some(t) => {
+                let gat_ident = &t.item.ident;
+                let (Some(induce), [GenericParam::Lifetime(lifetime)]) = (
+                    t.induce.as_ref(),
+                    t.item.generics.params.iter().collect::<Vec<_>>().as_slice(),
+                ) else {
+                    let msg = format!(
+                        "`{fn_ident}` returns `Self::{gat_ident}<'_>`, so `{gat_ident}` must use `#[induce(...)]` and take a single lifetime."
+                    );
+                    return Err(syn::Error::new_spanned(gat_ident, msg)
+                        .into_compile_error()
+                        .into());
+                };
+                if !matches!(kind, Kind::Ref)
+                    || is_comparator
+                    || short_circuit
+                    || field_path.is_some()
+                    || flow_input_ident.is_some()
+                    || !fn_args_list.is_empty()
+                    || !fn_generics.params.is_empty()
+                {
+                    let msg = format!(
+                        "`{fn_ident}` returns `Self::{gat_ident}<'_>` and so must take `&self` alone, outside of comparators, `short_circuit`, `field_path` and `signature(...)`."
+                    );
+                    return Err(syn::Error::new_spanned(&fn_ident, msg)
+                        .into_compile_error()
+                        .into());
+                }
+                for (step, ret) in [
+                    (NOTHING_FN_IDENT, &nothing_ret),
+                    (MERGE_FN_IDENT, &merge_ret),
+                    (MERGE_VAR_FN_IDENT, &merge_var_ret),
+                    (JOIN_FN_IDENT, &join_ret),
+                ] {
+                    let same = syn::parse2::<Type>(ret.clone())
+                        .ok()
+                        .is_some_and(|ty| self_gat_ret(&ty) == Some(gat_ident));
+                    if !same {
+                        let msg = format!(
+                            "`{fn_ident}` returns `Self::{gat_ident}<'_>`, so \"{step}\" must return it too; the `#[induce]` formulas of `{gat_ident}` give its type at each step."
+                        );
+                        return Err(syn::Error::new_spanned(ret, msg)
+                            .into_compile_error()
+                            .into());
+                    }
+                }
+                Some((gat_ident.clone(), lifetime.lifetime.clone(), induce.clone()))
+            }
+            None => None,
+        };
+        // Every generated step of an async behavior is itself async and awaits the next one.
+        let (asyncness, dot_await, allow_async_fn_in_trait) = if fn_async {
+            (
+                quote! { async },
+                quote! { .await },
+                quote! { #[allow(async_fn_in_trait)] },
+            )
+        } else {
+            (quote! {}, quote! {}, quote! {})
+        };
+        if is_comparator && !matches!(kind, Kind::Ty) && fn_arg_idents.len() < comparator_arity - 1
+        {
+            let msg = format!(
+                "A comparator of arity {comparator_arity} expects `{fn_ident}` to take {} further operands.",
+                comparator_arity - 1
+            );
+            return Err(syn::Error::new_spanned(&fn_ident, msg)
+                .into_compile_error()
+                .into());
+        }
+        let method_generic_params = if fn_generics.params.is_empty() {
+            quote! {}
+        } else {
+            let params = &fn_generics.params;
+            quote! { <#params> }
+        };
+        let method_where_clause = &fn_generics.where_clause;
+        let method_turbofish = if method_type_idents.is_empty() {
+            quote! {}
+        } else {
+            quote! { ::<#(#method_type_idents),*> }
         };
 
         let mut fn_args_inner_list = fn_args_list.clone();
@@ -2120,55 +3756,65 @@ impl State {
         let merge_fields_bounds_empty = merge_fields_bounds.is_empty();
         let merge_var_fields_bounds_empty = merge_var_fields_bounds.is_empty();
 
-        let merge_field_head_bounds = merge_field_head_bounds
-            .use_inner_trait(
-                &trait_ident,
-                &inductive_ident,
-                &mod_ident,
-                flow_input_ident.as_ref(),
-                flow_output_ident.as_ref(),
-            )
-            .into_tokens();
-        let merge_fields_bounds = merge_fields_bounds
-            .use_inner_trait(
-                &trait_ident,
-                &inductive_ident,
-                &mod_ident,
-                flow_input_ident.as_ref(),
-                flow_output_ident.as_ref(),
-            )
-            .into_tokens();
-        let merge_var_field_head_bounds = merge_var_field_head_bounds
-            .use_inner_trait(
-                &trait_ident,
-                &inductive_ident,
-                &mod_ident,
-                flow_input_ident.as_ref(),
-                flow_output_ident.as_ref(),
-            )
-            .into_tokens();
-        let merge_var_fields_bounds = merge_var_fields_bounds
-            .use_inner_trait(
-                &trait_ident,
-                &inductive_ident,
-                &mod_ident,
-                flow_input_ident.as_ref(),
-                flow_output_ident.as_ref(),
-            )
-            .into_tokens();
-        let join_fields_bounds = join_fields_bounds
-            .use_inner_trait(
-                &trait_ident,
-                &inductive_ident,
-                &mod_ident,
-                flow_input_ident.as_ref(),
-                flow_output_ident.as_ref(),
-            )
-            .into_tokens();
+        // The heads of a behavior returning an induced GAT go through the public trait instead.
+        let merge_field_head_public_bounds = merge_field_head_bounds.clone().into_tokens();
+        let merge_var_field_head_public_bounds = merge_var_field_head_bounds.clone().into_tokens();
+        // The heads of a `comparator(with = Rhs)` compare against the field of `Rhs` lined up with
+        // them, the tails and fields against all of `Rhs`.
+        let lined_up_ty = |var_idx: proc_macro2::TokenStream| {
+            comparator_with.as_ref().map(|With { rhs, by }| {
+                quote! { <::inception::shape::#by as ::inception::shape::LineUp<S, #rhs, #var_idx, IDX>>::Ty }
+            })
+        };
+        let merge_lined_up_ty = lined_up_ty(quote! { 0 });
+        let merge_var_lined_up_ty = lined_up_ty(quote! { VAR_IDX });
+        let rhs_ty = comparator_with
+            .as_ref()
+            .map(|With { rhs, .. }| quote! { #rhs });
+        let inner_bounds = |bounds: GenericBounds,
+                            is_primitive: proc_macro2::TokenStream,
+                            other: Option<&proc_macro2::TokenStream>| {
+            match other {
+                Some(other) => {
+                    bounds.use_heterogeneous_trait(&trait_ident, &mod_ident, &is_primitive, other)
+                }
+                None => bounds.use_inner_trait(
+                    &trait_ident,
+                    &inductive_ident,
+                    &mod_ident,
+                    flow_input_ident.as_ref(),
+                    flow_output_ident.as_ref(),
+                ),
+            }
+            .into_tokens()
+        };
+        let merge_field_head_bounds = inner_bounds(
+            merge_field_head_bounds,
+            quote! { <#merge_head_ident as ::inception::IsPrimitive<#property_ident>>::Is },
+            merge_lined_up_ty.as_ref(),
+        );
+        let merge_fields_bounds = inner_bounds(
+            merge_fields_bounds,
+            quote! { ::inception::False },
+            rhs_ty.as_ref(),
+        );
+        let merge_var_field_head_bounds = inner_bounds(
+            merge_var_field_head_bounds,
+            quote! { <#merge_var_head_ident as ::inception::IsPrimitive<#property_ident>>::Is },
+            merge_var_lined_up_ty.as_ref(),
+        );
+        let merge_var_fields_bounds = inner_bounds(
+            merge_var_fields_bounds,
+            quote! { ::inception::False },
+            rhs_ty.as_ref(),
+        );
+        let join_fields_bounds = inner_bounds(
+            join_fields_bounds,
+            quote! { ::inception::False },
+            rhs_ty.as_ref(),
+        );
 
         let dispatcher = kind.dispatcher();
-        let comp_dispatcher =
-            kind.comp_dispatcher(fn_arg_idents.get(0).unwrap_or(&format_ident!("_id")));
         let receiver = kind.receiver();
         let mutability = kind.mutability();
         let mutref = kind.refmut();
@@ -2183,8 +3829,17 @@ impl State {
         let liferef2 = kind.liferef2();
         let liferefelide = kind.liferefelide();
         let lifepunctelide = kind.lifepunctelide();
-        let access_bound = kind.access_bound(false, &merge_head_ident);
-        let try_access_bound = kind.access_bound(true, &merge_var_head_ident);
+        // `fallible` steps see the head's `Ok` value rather than the head field itself.
+        let (access_bound, try_access_bound) = match &fallible_ok {
+            Some(ok) => (
+                quote! { + Access<Out = #ok> },
+                quote! { + TryAccess<Out = #ok, Err = OwnedEnumAccessError<#ok, S, VAR_IDX, IDX>> },
+            ),
+            None => (
+                kind.access_bound(false, &merge_head_ident),
+                kind.access_bound(true, &merge_var_head_ident),
+            ),
+        };
         let phantom_bound = kind.phantom_bound();
 
         let fields_ident = kind.fields();
@@ -2221,7 +3876,11 @@ impl State {
         } else {
             quote! { , #fn_args_inner_list }
         };
-        let fields_fn = kind.fields_fn(&property);
+        let fields_fn = if merge_variant.is_some() {
+            kind.grouped_fields_fn(&property)
+        } else {
+            kind.fields_fn(&property)
+        };
         let split_impl = kind.split_impl(&property, &wrapper);
         let split_trait_ident = kind.split_trait_ident();
         let split_fn_ident = kind.split_fn_ident();
@@ -2329,18 +3988,29 @@ impl State {
         } else {
             quote! {}
         };
-        let needs_named_ret_lifetime =
-            has_output_assoc && matches!(kind, Kind::Ref | Kind::Mut) && !flow_assoc_borrow_mode;
-        let internal_trait_generic_args = if flow_input_from_assoc && !type_params.is_empty() {
-            quote! { , #(#type_params),* }
-        } else {
-            quote! {}
-        };
-        let internal_trait_single_input_args = if flow_input_from_assoc && !type_params.is_empty() {
-            quote! { , In #internal_trait_generic_args }
-        } else {
-            quote! {}
-        };
+        let needs_named_ret_lifetime =
+            has_output_assoc && matches!(kind, Kind::Ref | Kind::Mut) && !flow_assoc_borrow_mode;
+        let internal_trait_generic_args =
+            if (flow_input_from_assoc || comparator_with.is_some()) && !type_params.is_empty() {
+                quote! { , #(#type_params),* }
+            } else {
+                quote! {}
+            };
+        let internal_trait_single_input_args =
+            if (flow_input_from_assoc || comparator_with.is_some()) && !type_params.is_empty() {
+                quote! { , In #internal_trait_generic_args }
+            } else {
+                quote! {}
+            };
+        // The head of a `comparator(with = Rhs)` compares against the field lined up with it.
+        let (merge_head_rhs_args, merge_var_head_rhs_args) =
+            match (&merge_lined_up_ty, &merge_var_lined_up_ty) {
+                (Some(ty), Some(var_ty)) => (quote! { , In, #ty }, quote! { , In, #var_ty }),
+                _ => (
+                    internal_trait_single_input_args.clone(),
+                    internal_trait_single_input_args.clone(),
+                ),
+            };
         let ret_lifepunct = if needs_named_ret_lifetime {
             quote! { #ret_lifetime, }
         } else {
@@ -2361,7 +4031,7 @@ impl State {
             }
         } else {
             quote! {
-                <#merge_head_ident as #inner_trait<<#merge_head_ident as ::inception::IsPrimitive<#property>>::Is, In #internal_trait_single_input_args>>::OutTy
+                <#merge_head_ident as #inner_trait<<#merge_head_ident as ::inception::IsPrimitive<#property>>::Is, In #merge_head_rhs_args>>::OutTy
             }
         };
         let merge_split_right_ty = quote! {
@@ -2370,7 +4040,9 @@ impl State {
         let merge_split_right_ty_named_ret = quote! {
             #wrapper<#ret_liferef <#wrapper<#ret_liferef List<(#field<#ret_lifepunct #merge_head_ident, S, IDX>, F)>> as #split_trait_ident #split_trait_args_ret>::Right>
         };
-        let merge_inductive_self_ty = if needs_named_ret_lifetime {
+        let merge_inductive_self_ty = if gat_ret.is_some() {
+            quote! { #wrapper<&'_ List<(#field<#ret_lifetime, #merge_head_ident, S, IDX>, F)>> }
+        } else if needs_named_ret_lifetime {
             quote! { #wrapper<#ret_liferef List<(#field<#ret_lifepunct #merge_head_ident, S, IDX>, F)>> }
         } else {
             quote! { #wrapper<#liferefelide List<(#field<#lifepunctelide #merge_head_ident, S, IDX>, F)>> }
@@ -2381,7 +4053,7 @@ impl State {
             }
         } else {
             quote! {
-                <#merge_var_head_ident as #inner_trait<<#merge_var_head_ident as ::inception::IsPrimitive<#property>>::Is, In #internal_trait_single_input_args>>::OutTy
+                <#merge_var_head_ident as #inner_trait<<#merge_var_head_ident as ::inception::IsPrimitive<#property>>::Is, In #merge_var_head_rhs_args>>::OutTy
             }
         };
         let merge_var_split_right_ty = quote! {
@@ -2390,13 +4062,31 @@ impl State {
         let merge_var_split_right_ty_named_ret = quote! {
             #wrapper<#ret_liferef <#wrapper<#ret_liferef List<(#var_field<#ret_lifepunct #merge_var_head_ident, S, VAR_IDX, IDX>, F)>> as #split_trait_ident #split_trait_args_ret>::Right>
         };
-        let merge_var_inductive_self_ty = if needs_named_ret_lifetime {
+        let merge_var_inductive_self_ty = if gat_ret.is_some() {
+            quote! { #wrapper<&'_ List<(#var_field<#ret_lifetime, #merge_var_head_ident, S, VAR_IDX, IDX>, F)>> }
+        } else if needs_named_ret_lifetime {
             quote! { #wrapper<#ret_liferef List<(#var_field<#ret_lifepunct #merge_var_head_ident, S, VAR_IDX, IDX>, F)>> }
         } else {
             quote! { #wrapper<#liferefelide List<(#var_field<#lifepunctelide #merge_var_head_ident, S, VAR_IDX, IDX>, F)>> }
         };
-        let join_wrapper_fields_ty = quote! {
-            #wrapper<#liferef1 <T as Inception<#property>>::#fields_ident #bracketlife2>
+        let join_group_ty =
+            quote! { ::inception::Group<#property, T, <T as ::inception::DataType>::Ty> };
+        let (join_wrapper_fields_ty, join_group_bound) = if merge_variant.is_some() {
+            (
+                quote! {
+                    #wrapper<#liferef1 <<T as Inception<#property>>::#fields_ident #bracketlife2 as #join_group_ty>::Out>
+                },
+                quote! {
+                    for<#life2> <T as Inception<#property>>::#fields_ident #bracketlife2: #join_group_ty,
+                },
+            )
+        } else {
+            (
+                quote! {
+                    #wrapper<#liferef1 <T as Inception<#property>>::#fields_ident #bracketlife2>
+                },
+                quote! {},
+            )
         };
         let join_wrapper_fields_ty_named_ret = quote! {
             #wrapper<#ret_liferef <T as Inception<#property>>::#fields_ident <#ret_lifetime>>
@@ -2702,6 +4392,117 @@ impl State {
         } else {
             substitute_ret_ident(&join_ret, &join_fields_ident, &join_wrapper_fields_ty)
         };
+        let induce_head_placeholder = format_ident!("Head");
+        let induce_tail_placeholder = format_ident!("Tail");
+        let induce_fields_placeholder = format_ident!("Fields");
+        let induce_in_placeholder = format_ident!("In");
+        let induce_out_placeholder = format_ident!("Out");
+        // Steps returning an induced GAT take their types from its formulas, with the head viewed
+        // through the public trait for the lifetime of its field and the tail through `Inductive`.
+        let (
+            nothing_ret,
+            merge_ret,
+            merge_var_ret,
+            join_ret,
+            merge_ret_inductive,
+            merge_var_ret_inductive,
+        ) =
+            match &gat_ret {
+                Some((gat_ident, gat_lifetime, induce)) => {
+                    let field_lifetime = syn::Lifetime::new("'a", proc_macro2::Span::call_site());
+                    let static_lifetime =
+                        syn::Lifetime::new("'static", proc_macro2::Span::call_site());
+                    let formula = |ty: &Type,
+                               lifetime: &syn::Lifetime,
+                               head: Option<&Ident>,
+                               projections: &[(&Ident, proc_macro2::TokenStream)]| {
+                    let mut ty = syn::parse2::<Type>(rename_lifetime(
+                        quote! { #ty },
+                        gat_lifetime,
+                        lifetime,
+                    ))
+                    .expect("renaming a lifetime keeps a type");
+                    for (source, replacement) in projections {
+                        let replacement = syn::parse2::<Type>(replacement.clone())
+                            .expect("projections are replaced by types");
+                        replace_assoc_projection(
+                            &mut ty,
+                            &trait_ident,
+                            gat_ident,
+                            source,
+                            &replacement,
+                        );
+                    }
+                    let ty = quote! { #ty };
+                    match head {
+                        Some(head) => {
+                            substitute_ret_ident(&ty, &induce_head_placeholder, &quote! { #head })
+                        }
+                        None => ty,
+                    }
+                };
+                    let merge = |head: &Ident,
+                                 lifetime: &syn::Lifetime,
+                                 tail: proc_macro2::TokenStream,
+                                 ty: &Type| {
+                        formula(
+                            ty,
+                            lifetime,
+                            Some(head),
+                            &[
+                                (
+                                    &induce_head_placeholder,
+                                    quote! { <#head as #trait_ident>::#gat_ident<#lifetime> },
+                                ),
+                                (&induce_tail_placeholder, tail),
+                            ],
+                        )
+                    };
+                    let tail_ret = |tail: &Ident| quote! { <#tail as #inner_trait>::Ret };
+                    let node_tail_ret = quote! { #flow_assoc_merge_ret_ident };
+                    (
+                        formula(&induce.base.ty, &static_lifetime, None, &[]),
+                        merge(
+                            &merge_head_ident,
+                            &field_lifetime,
+                            tail_ret(&merge_fields_ident),
+                            &induce.merge.ty,
+                        ),
+                        merge(
+                            &merge_var_head_ident,
+                            &field_lifetime,
+                            tail_ret(&merge_var_fields_ident),
+                            &induce.merge_variant.ty,
+                        ),
+                        formula(
+                            &induce.join.ty,
+                            &static_lifetime,
+                            None,
+                            &[(&induce_fields_placeholder, tail_ret(&join_fields_ident))],
+                        ),
+                        merge(
+                            &merge_head_ident,
+                            &ret_lifetime,
+                            node_tail_ret.clone(),
+                            &induce.merge.ty,
+                        ),
+                        merge(
+                            &merge_var_head_ident,
+                            &ret_lifetime,
+                            node_tail_ret,
+                            &induce.merge_variant.ty,
+                        ),
+                    )
+                }
+                None => (
+                    nothing_ret,
+                    merge_ret,
+                    merge_var_ret,
+                    join_ret,
+                    merge_ret_inductive,
+                    merge_var_ret_inductive,
+                ),
+            };
         let trait_where_clause = &trait_generics.where_clause;
         let trait_generic_params = {
             let params = &trait_generics.params;
@@ -2725,21 +4526,25 @@ impl State {
             if flow_input_from_assoc && !trait_generics.params.is_empty() {
                 let params = &trait_generics.params;
                 quote! { , #params }
+            } else if let Some(rhs) = &rhs_ty {
+                quote! { , #rhs = Self }
             } else {
                 quote! {}
             };
-        let internal_trait_impl_generic_defs =
-            if flow_input_from_assoc && !type_param_defs_no_default.is_empty() {
-                quote! { , #(#type_param_defs_no_default),* }
-            } else {
-                quote! {}
-            };
+        let internal_trait_impl_generic_defs = if (flow_input_from_assoc
+            || comparator_with.is_some())
+            && !type_param_defs_no_default.is_empty()
+        {
+            quote! { , #(#type_param_defs_no_default),* }
+        } else {
+            quote! {}
+        };
         let fields_input_trait_use_params = if type_params.is_empty() {
             quote! { <T> }
         } else {
             quote! { <T, #(#type_params),*> }
         };
-        let compat_impl = if flow_input_ident.is_some() {
+        let compat_impl = if flow_input_ident.is_some() || comparator_with.is_some() {
             quote! {}
         } else {
             quote! {
@@ -2760,12 +4565,12 @@ impl State {
         };
         let trait_bound_with_in = if flow_input_from_assoc {
             let Some(input_assoc_ident) = input_assoc_ident.as_ref() else {
-                return syn::Error::new_spanned(
+                return Err(syn::Error::new_spanned(
                     trait_ident,
                     "Associated-input mode requires a trait associated type named `Input` or `In`.",
                 )
                 .into_compile_error()
-                .into();
+                .into());
             };
             if type_params.is_empty() {
                 quote! { super::#trait_ident<#input_assoc_ident = In> }
@@ -2776,7 +4581,7 @@ impl State {
             match (flow_input_ident.as_ref(), flow_output_ident.as_ref()) {
                 (Some(_), Some(_)) => quote! { super::#trait_ident<In, Out> },
                 (Some(_), None) => quote! { super::#trait_ident<In> },
-                (None, _) => quote! { super::#trait_ident },
+                (None, _) => quote! { super::#trait_ident #trait_generic_args },
             }
         };
         let trait_path_for_ufcs = if type_params.is_empty() {
@@ -2794,13 +4599,13 @@ impl State {
                     quote! { <T, In> }
                 }
             }
-            (None, _) => quote! { <T, In> },
+            (None, _) => quote! { <T, In #internal_trait_impl_generic_defs> },
         };
         let primitive_impl_trait_args =
             match (flow_input_ident.as_ref(), flow_output_ident.as_ref()) {
                 (Some(_), Some(_)) => quote! { <True, In, Out #internal_trait_generic_args> },
                 (Some(_), None) => quote! { <True, In #internal_trait_single_input_args> },
-                (None, _) => quote! { <True, In #internal_trait_generic_args> },
+                (None, _) => quote! { <True, In #internal_trait_single_input_args> },
             };
         let blanket_impl_head = if flow_input_from_assoc {
             quote! { impl #trait_impl_generic_params #trait_ident #trait_generic_args for T }
@@ -2810,10 +4615,14 @@ impl State {
                     quote! { impl<T, #flow_in, #flow_out> #trait_ident<#flow_in, #flow_out> for T }
                 }
                 (Some(flow), None) => quote! { impl<T, #flow> #trait_ident<#flow> for T },
-                (None, _) => quote! { impl<T> #trait_ident for T },
+                (None, _) => {
+                    quote! { impl #trait_impl_generic_params #trait_ident #trait_generic_args for T }
+                }
             }
         };
-        let blanket_inner_bound = if flow_input_from_assoc {
+        let blanket_inner_bound = if gat_ret.is_some() {
+            quote! { Inception<#property> + Meta + for<'a> #mod_ident::JoinFields<'a> }
+        } else if flow_input_from_assoc {
             quote! { #inner_trait<::inception::False, #inferred_input_inner_trait_args> }
         } else {
             match (flow_input_ident.as_ref(), flow_output_ident.as_ref()) {
@@ -2821,21 +4630,26 @@ impl State {
                     quote! { #inner_trait<::inception::False, #flow_in, #flow_out, Ret = #flow_out> }
                 }
                 (Some(flow), None) => quote! { #inner_trait<::inception::False, #flow> },
+                (None, _) if comparator_with.is_some() => quote! {
+                    #inner_trait<::inception::False, (), () #internal_trait_generic_args, Ret = #fn_ret_public>
+                },
                 (None, _) => quote! { #inner_trait<::inception::False, Ret = #fn_ret_public> },
             }
         };
-        let blanket_dispatch_body = if flow_input_from_assoc {
+        let blanket_dispatch_body = if gat_ret.is_some() {
+            quote! { <Self as #mod_ident::JoinFields<'_>>::join_fields(self) }
+        } else if flow_input_from_assoc {
             if matches!(kind, Kind::Ty) {
                 quote! {
-                    <Self as #inner_trait<::inception::False, #inferred_input_inner_trait_args>>::#inner_fn(#fn_arg_idents)
+                    <Self as #inner_trait<::inception::False, #inferred_input_inner_trait_args>>::#inner_fn #method_turbofish(#fn_arg_idents) #dot_await
                 }
             } else {
                 quote! {
-                    <Self as #inner_trait<::inception::False, #inferred_input_inner_trait_args>>::#inner_fn(self, #fn_arg_idents)
+                    <Self as #inner_trait<::inception::False, #inferred_input_inner_trait_args>>::#inner_fn #method_turbofish(self, #fn_arg_idents) #dot_await
                 }
             }
         } else {
-            quote! { #dispatcher #inner_fn(#fn_arg_idents) }
+            quote! { #dispatcher #inner_fn #method_turbofish(#fn_arg_idents) #dot_await }
         };
         let primitive_ret = if let Some(output_assoc_ident) = output_assoc_ident.as_ref() {
             if flow_input_from_assoc {
@@ -2848,12 +4662,12 @@ impl State {
         };
         let primitive_dispatch_body = if flow_input_from_assoc {
             if matches!(kind, Kind::Ty) {
-                quote! { <T as #trait_path_for_ufcs>::#fn_ident(#fn_arg_idents) }
+                quote! { <T as #trait_path_for_ufcs>::#fn_ident #method_turbofish(#fn_arg_idents) #dot_await }
             } else {
-                quote! { <T as #trait_path_for_ufcs>::#fn_ident(self, #fn_arg_idents) }
+                quote! { <T as #trait_path_for_ufcs>::#fn_ident #method_turbofish(self, #fn_arg_idents) #dot_await }
             }
         } else {
-            quote! { #dispatcher #fn_ident( #fn_arg_idents ) }
+            quote! { #dispatcher #fn_ident #method_turbofish( #fn_arg_idents ) #dot_await }
         };
         let primitive_out_ty = if flow_two_generic {
             quote! { Out }
@@ -2902,7 +4716,11 @@ impl State {
                 } else {
                     quote! { Ret<#(#assoc_use_args),*> }
                 };
-                if t.induce.is_some() {
+                if gat_ret.as_ref().is_some_and(|(gat_ident, ..)| gat_ident == ident) {
+                    Some(quote! {
+                        type #ident #assoc_impl_generics = <T as #mod_ident::JoinFields<#(#assoc_use_args),*>>::Ret #assoc_where_clause;
+                    })
+                } else if t.induce.is_some() {
                     let helper_ident = format_ident!("__InceptionInduce{}", ident);
                     if flow_input_from_assoc {
                         Some(quote! {
@@ -2956,9 +4774,14 @@ impl State {
         } else {
             quote! { + #trait_supertraits }
         };
+        let is_gat_ret = |t: &&AssocTypeSpec| {
+            gat_ret
+                .as_ref()
+                .is_some_and(|(gat_ident, ..)| *gat_ident == t.item.ident)
+        };
         let induced_blanket_where_preds = assoc_types
             .iter()
-            .filter(|t| t.induce.is_some())
+            .filter(|t| t.induce.is_some() && !is_gat_ret(t))
             .map(|t| {
                 let helper_ident = format_ident!("__InceptionInduce{}", t.item.ident);
                 if flow_input_from_assoc {
@@ -3000,69 +4823,137 @@ impl State {
                 quote! {}
             };
 
-        let placeholder = format_ident!("_");
-        let (merge_arg_head, merge_arg_tail) = (
-            merge_arg_idents.get(0).unwrap_or(&placeholder),
-            merge_arg_idents.get(1).unwrap_or(&placeholder),
-        );
-        let (merge_var_arg_head, merge_var_arg_tail) = (
-            merge_arg_idents.get(0).unwrap_or(&placeholder),
-            merge_arg_idents.get(1).unwrap_or(&placeholder),
-        );
-        let join_arg_fields = join_arg_idents
-            .get(0)
-            .cloned()
-            .unwrap_or(placeholder.clone());
+        // Every operand after the first is split alongside it, into the head and tail idents named
+        // by the comparator's merge steps.
+        let operand_dispatchers = (0..comparator_arity - 1)
+            .map(|i| kind.comp_dispatcher(fn_arg_idents.get(i).unwrap_or(&format_ident!("_id"))))
+            .collect::<Vec<_>>();
+        let split_operands = |idents: &Punctuated<Ident, Comma>| {
+            let heads = idents.iter().step_by(2);
+            let tails = idents.iter().skip(1).step_by(2);
+            quote! {
+                #(
+                    let (#mutability #heads, #mutability #tails) = #operand_dispatchers #split_fn_ident();
+                    let #mutability #tails = #wrapper(#tails);
+                )*
+            }
+        };
+        // A `comparator(with = Rhs)` rather hands its steps the field of `Rhs` lined up with the
+        // head, then `Rhs` itself.
+        let line_up_operands = |idents: &Punctuated<Ident, Comma>,
+                                var_idx: proc_macro2::TokenStream| {
+            let (Some(With { rhs, by }), Some(operand)) = (&comparator_with, fn_arg_idents.first())
+            else {
+                return quote! {};
+            };
+            let mut idents = idents.iter();
+            let (Some(head), Some(other)) = (idents.next(), idents.next()) else {
+                return quote! {};
+            };
+            let rebind = (other != operand).then(|| quote! { let #other = #operand; });
+            quote! {
+                let #head = <::inception::shape::#by as ::inception::shape::LineUp<S, #rhs, #var_idx, IDX>>::line_up(#operand);
+                #rebind
+            }
+        };
         let comparator_fields_fn = if is_comparator {
-            kind.comparator_fields_fn(&join_arg_fields, &property)
+            let fields_fns = join_arg_idents
+                .iter()
+                .map(|ident| kind.comparator_fields_fn(ident, &property));
+            quote! { #(#fields_fns)* }
         } else {
             quote! {}
         };
-        let merge_comparator_body = if is_comparator {
-            quote! {
-                let (#mutability #merge_arg_head, #mutability #merge_arg_tail) = #comp_dispatcher #split_fn_ident();
-                let #mutability #merge_arg_tail = #wrapper(#merge_arg_tail);
-            }
+        let merge_comparator_body = if comparator_with.is_some() {
+            line_up_operands(&merge_arg_idents, quote! { 0 })
+        } else if is_comparator {
+            split_operands(&merge_arg_idents)
         } else {
             quote! {}
         };
-        let merge_var_comparator_body = if is_comparator {
-            quote! {
-                    let (#mutability #merge_var_arg_head, #mutability #merge_var_arg_tail) = #comp_dispatcher #split_fn_ident();
-                    let #mutability #merge_var_arg_tail = #wrapper(#merge_var_arg_tail);
-            }
+        let merge_var_comparator_body = if comparator_with.is_some() {
+            line_up_operands(&merge_var_arg_idents, quote! { VAR_IDX })
+        } else if is_comparator {
+            split_operands(&merge_var_arg_idents)
         } else {
             quote! {}
         };
-        let join_comparator_body = if is_comparator {
+        let join_comparator_body = if is_comparator && comparator_with.is_none() {
+            let join_operands = join_arg_idents.iter();
             quote! {
                 #comparator_fields_fn
-                let #join_arg_fields = #wrapper(#mutref #join_arg_fields);
+                #(let #join_operands = #wrapper(#mutref #join_operands);)*
             }
         } else {
             quote! {}
         };
-        let merge_extra_tuple_ty = if merge_extra_generics.is_empty() {
+        let merge_extra_tuple_ty = if comparator_with.is_some() {
+            quote! { L2 }
+        } else if merge_extra_generics.is_empty() {
             quote! { () }
         } else {
             quote! { (#(#merge_extra_generics),*,) }
         };
-        let merge_var_extra_tuple_ty = if merge_var_extra_generics.is_empty() {
+        let merge_var_extra_tuple_ty = if comparator_with.is_some() {
+            quote! { L2 }
+        } else if merge_var_extra_generics.is_empty() {
             quote! { () }
         } else {
             quote! { (#(#merge_var_extra_generics),*,) }
         };
+        let (merge_extra_param, lined_up_lifetime, lined_up_generic) = if comparator_with.is_some()
+        {
+            (quote! { L2 }, quote! { '__inception_rhs, }, quote! { L2, })
+        } else {
+            (quote! { Extra }, quote! {}, quote! {})
+        };
+        let line_up_bounds = |var_idx: proc_macro2::TokenStream,
+                              ty: &Option<proc_macro2::TokenStream>| {
+            match (&comparator_with, ty) {
+                (Some(With { rhs, by }), Some(ty)) => (
+                    quote! { ::inception::shape::#by: ::inception::shape::LineUp<S, #rhs, #var_idx, IDX>, },
+                    quote! {
+                        #ty: '__inception_rhs,
+                        L2: ::inception::shape::Found<Out = &'__inception_rhs #ty>,
+                    },
+                ),
+                _ => (quote! {}, quote! {}),
+            }
+        };
+        let (merge_line_up_bound, merge_lined_up_bounds) =
+            line_up_bounds(quote! { 0 }, &merge_lined_up_ty);
+        let (merge_var_line_up_bound, merge_var_lined_up_bounds) =
+            line_up_bounds(quote! { VAR_IDX }, &merge_var_lined_up_ty);
+        let (merge_head_inner_trait, merge_var_head_inner_trait, tail_inner_trait) = match (
+            &merge_lined_up_ty,
+            &merge_var_lined_up_ty,
+            &rhs_ty,
+        ) {
+            (Some(ty), Some(var_ty), Some(rhs)) => (
+                quote! { #inner_trait<<#merge_head_ident as ::inception::IsPrimitive<#property>>::Is, (), (), #ty> },
+                quote! { #inner_trait<<#merge_var_head_ident as ::inception::IsPrimitive<#property>>::Is, (), (), #var_ty> },
+                quote! { #inner_trait<::inception::False, (), (), #rhs> },
+            ),
+            _ => (
+                inner_trait.clone(),
+                inner_trait.clone(),
+                inner_trait.clone(),
+            ),
+        };
         let merge_field_impl_generics = if flow_mode {
             quote! { <#lifepunct1 #merge_head_ident, S, const IDX: usize, F, L, #merge_fields_ident, #(#merge_extra_generics,)* In, Out #internal_trait_impl_generic_defs> }
         } else {
-            quote! { <#lifepunct1 #merge_head_ident, S, const IDX: usize, F, L, #merge_fields_ident, In #internal_trait_impl_generic_defs> }
+            quote! { <#lined_up_lifetime #lifepunct1 #merge_head_ident, S, const IDX: usize, F, L, #merge_fields_ident, #lined_up_generic In #internal_trait_impl_generic_defs> }
         };
         let merge_field_impl_trait_args = if flow_mode {
             quote! { <L, #merge_fields_ident, In, Out, #merge_extra_tuple_ty #internal_trait_generic_args> }
         } else {
             quote! { <L, #merge_fields_ident, In, In, #merge_extra_tuple_ty #internal_trait_generic_args> }
         };
-        let merge_field_head_bound = if flow_two_generic {
+        let gat_mode = gat_ret.is_some();
+        let merge_field_head_bound = if gat_mode {
+            quote! { #trait_ident #merge_field_head_public_bounds + ::inception::IsPrimitive<#property> }
+        } else if flow_two_generic {
             quote! { ::core::marker::Sized #merge_field_head_bounds + ::inception::IsPrimitive<#property> }
         } else if flow_mode {
             if flow_assoc_borrow_mode {
@@ -3071,7 +4962,7 @@ impl State {
                 quote! { #inner_trait<<#merge_head_ident as ::inception::IsPrimitive<#property>>::Is, In #internal_trait_single_input_args> #merge_field_head_bounds + ::inception::IsPrimitive<#property> }
             }
         } else {
-            quote! { #inner_trait #merge_field_head_bounds + ::inception::IsPrimitive<#property> }
+            quote! { #merge_head_inner_trait #merge_field_head_bounds + ::inception::IsPrimitive<#property> }
         };
         let merge_field_tail_bound = if flow_two_generic {
             quote! { Fields #merge_fields_bounds + ::inception::IsPrimitive<#property> }
@@ -3082,7 +4973,7 @@ impl State {
                 quote! { Fields + #inner_trait<::inception::False, #merge_head_out_ty, Out #internal_trait_generic_args> #merge_fields_bounds + ::inception::IsPrimitive<#property> }
             }
         } else {
-            quote! { Fields + #inner_trait #merge_fields_bounds + ::inception::IsPrimitive<#property> }
+            quote! { Fields + #tail_inner_trait #merge_fields_bounds + ::inception::IsPrimitive<#property> }
         };
         let merge_field_impl_out_ty = if flow_mode {
             quote! { Out }
@@ -3092,26 +4983,28 @@ impl State {
         let merge_variant_impl_generics = if flow_mode {
             quote! { <#lifepunct1 #merge_var_head_ident, S, const VAR_IDX: usize, const IDX: usize, F, L, #merge_var_fields_ident, #(#merge_var_extra_generics,)* In, Out #internal_trait_impl_generic_defs> }
         } else {
-            quote! { <#lifepunct1 #merge_var_head_ident, S, const VAR_IDX: usize, const IDX: usize, F, L, #merge_var_fields_ident, In #internal_trait_impl_generic_defs> }
+            quote! { <#lined_up_lifetime #lifepunct1 #merge_var_head_ident, S, const VAR_IDX: usize, const IDX: usize, F, L, #merge_var_fields_ident, #lined_up_generic In #internal_trait_impl_generic_defs> }
         };
         let merge_variant_impl_trait_args = if flow_mode {
             quote! { <L, #merge_var_fields_ident, In, Out, #merge_var_extra_tuple_ty #internal_trait_generic_args> }
         } else {
             quote! { <L, #merge_var_fields_ident, In, In, #merge_var_extra_tuple_ty #internal_trait_generic_args> }
         };
-        let merge_variant_head_bound = if flow_two_generic {
+        let merge_variant_head_bound = if gat_mode {
+            quote! { #trait_ident #merge_var_field_head_public_bounds + ::inception::IsPrimitive<#property> }
+        } else if flow_two_generic {
             quote! { ::core::marker::Sized #merge_var_field_head_bounds + ::inception::IsPrimitive<#property> }
         } else if flow_mode {
             quote! { #inner_trait<<#merge_var_head_ident as ::inception::IsPrimitive<#property>>::Is, In #internal_trait_single_input_args> #merge_var_field_head_bounds + ::inception::IsPrimitive<#property> }
         } else {
-            quote! { #inner_trait #merge_var_field_head_bounds + ::inception::IsPrimitive<#property> }
+            quote! { #merge_var_head_inner_trait #merge_var_field_head_bounds + ::inception::IsPrimitive<#property> }
         };
         let merge_variant_tail_bound = if flow_two_generic {
             quote! { Fields #merge_var_fields_bounds + ::inception::IsPrimitive<#property> }
         } else if flow_mode {
             quote! { Fields + #inner_trait<::inception::False, #merge_var_head_out_ty, Out #internal_trait_generic_args> #merge_var_fields_bounds + ::inception::IsPrimitive<#property> }
         } else {
-            quote! { Fields + #inner_trait #merge_var_fields_bounds + ::inception::IsPrimitive<#property> }
+            quote! { Fields + #tail_inner_trait #merge_var_fields_bounds + ::inception::IsPrimitive<#property> }
         };
         let merge_variant_impl_out_ty = if flow_mode {
             quote! { Out }
@@ -3135,7 +5028,7 @@ impl State {
                 quote! { #inner_trait<::inception::False, In, Out #internal_trait_generic_args> #join_fields_bounds + ::inception::IsPrimitive<#property> }
             }
         } else {
-            quote! { #inner_trait #join_fields_bounds + ::inception::IsPrimitive<#property> }
+            quote! { #tail_inner_trait #join_fields_bounds + ::inception::IsPrimitive<#property> }
         };
         let join_impl_out_ty = if flow_mode {
             quote! { Out }
@@ -3160,9 +5053,9 @@ impl State {
         let join_call_trait_args = if flow_mode {
             quote! { <_, In, Out #internal_trait_generic_args> }
         } else {
-            quote! { <_, In #internal_trait_generic_args> }
+            quote! { <_, In #internal_trait_single_input_args> }
         };
-        let ret_lifetime_decl = if needs_named_ret_lifetime {
+        let ret_lifetime_decl = if needs_named_ret_lifetime || gat_mode {
             quote! { #ret_lifetime, }
         } else {
             quote! {}
@@ -3172,16 +5065,9 @@ impl State {
         let merge_split_trait_generic = quote! {
             #split_trait_ident<#property, Left = #field<#lifepunct2 #merge_head_ident, S, IDX>, Right = F>
         };
-        let merge_split_bound = if needs_named_ret_lifetime {
-            quote! {
-                #split_for_2 #wrapper<#liferef1 List<(#field<#lifepunct2 #merge_head_ident, S, IDX>, F)>>:
-                    #merge_split_trait_generic,
-            }
-        } else {
-            quote! {
-                #split_for_2 #wrapper<#liferef1 List<(#field<#lifepunct2 #merge_head_ident, S, IDX>, F)>>:
-                    #merge_split_trait_generic,
-            }
+        let merge_split_bound = quote! {
+            #split_for_2 #wrapper<#liferef1 List<(#field<#lifepunct2 #merge_head_ident, S, IDX>, F)>>:
+                #merge_split_trait_generic,
         };
         let merge_tail_extra_bounds = if flow_assoc_borrow_mode {
             quote! {}
@@ -3199,7 +5085,12 @@ impl State {
         } else {
             quote! { Fields }
         };
-        let merge_tail_bound = if flow_two_generic {
+        let gat_tail_bound = quote! {
+            for<'a> #wrapper<&'a F>: #inner_trait<Ret = #flow_assoc_merge_ret_ident> + Fields,
+        };
+        let merge_tail_bound = if gat_mode {
+            gat_tail_bound.clone()
+        } else if flow_two_generic {
             quote! {
                 #split_for_3 #merge_split_right_ty:
                     #merge_tail_two_generic_rhs,
@@ -3235,22 +5126,12 @@ impl State {
         } else {
             quote! {}
         };
-        let merge_var_split_bound = if needs_named_ret_lifetime {
-            let merge_var_split_trait_generic = quote! {
-                #split_trait_ident<#property, Left = #var_field<#lifepunct2 #merge_var_head_ident, S, VAR_IDX, IDX>, Right = F>
-            };
-            quote! {
-                #split_for_2 #wrapper<#liferef1 List<(#var_field<#lifepunct2 #merge_var_head_ident, S, VAR_IDX, IDX>, F)>>:
-                    #merge_var_split_trait_generic,
-            }
-        } else {
-            let merge_var_split_trait_generic = quote! {
-                #split_trait_ident<#property, Left = #var_field<#lifepunct2 #merge_var_head_ident, S, VAR_IDX, IDX>, Right = F>
-            };
-            quote! {
-                #split_for_2 #wrapper<#liferef1 List<(#var_field<#lifepunct2 #merge_var_head_ident, S, VAR_IDX, IDX>, F)>>:
-                    #merge_var_split_trait_generic,
-            }
+        let merge_var_split_trait_generic = quote! {
+            #split_trait_ident<#property, Left = #var_field<#lifepunct2 #merge_var_head_ident, S, VAR_IDX, IDX>, Right = F>
+        };
+        let merge_var_split_bound = quote! {
+            #split_for_2 #wrapper<#liferef1 List<(#var_field<#lifepunct2 #merge_var_head_ident, S, VAR_IDX, IDX>, F)>>:
+                #merge_var_split_trait_generic,
         };
         let merge_var_tail_extra_bounds = if merge_var_extra_generics.is_empty() {
             quote! { #merge_var_fields_bounds }
@@ -3266,7 +5147,9 @@ impl State {
         } else {
             quote! { Fields }
         };
-        let merge_var_tail_bound = if flow_two_generic {
+        let merge_var_tail_bound = if gat_mode {
+            gat_tail_bound.clone()
+        } else if flow_two_generic {
             quote! {
                 #split_for_3 #merge_var_split_right_ty:
                     #merge_var_tail_two_generic_rhs,
@@ -3304,7 +5187,9 @@ impl State {
         } else {
             quote! {}
         };
-        let merge_inner_head_bound = if flow_two_generic {
+        let merge_inner_head_bound = if gat_mode {
+            merge_field_head_bound.clone()
+        } else if flow_two_generic {
             quote! { ::inception::IsPrimitive<#property> }
         } else {
             if flow_assoc_borrow_mode {
@@ -3313,7 +5198,7 @@ impl State {
                 }
             } else {
                 quote! {
-                    #inner_trait<<#merge_head_ident as ::inception::IsPrimitive<#property>>::Is, In #internal_trait_single_input_args> #merge_inner_head_extra_bounds + ::inception::IsPrimitive<#property>
+                    #inner_trait<<#merge_head_ident as ::inception::IsPrimitive<#property>>::Is, In #merge_head_rhs_args> #merge_inner_head_extra_bounds + ::inception::IsPrimitive<#property>
                 }
             }
         };
@@ -3322,11 +5207,13 @@ impl State {
         } else {
             quote! {}
         };
-        let merge_var_inner_head_bound = if flow_two_generic {
+        let merge_var_inner_head_bound = if gat_mode {
+            merge_variant_head_bound.clone()
+        } else if flow_two_generic {
             quote! { ::inception::IsPrimitive<#property> }
         } else {
             quote! {
-                #inner_trait<<#merge_var_head_ident as ::inception::IsPrimitive<#property>>::Is, In #internal_trait_single_input_args> #merge_var_inner_head_extra_bounds + ::inception::IsPrimitive<#property>
+                #inner_trait<<#merge_var_head_ident as ::inception::IsPrimitive<#property>>::Is, In #merge_var_head_rhs_args> #merge_var_inner_head_extra_bounds + ::inception::IsPrimitive<#property>
             }
         };
         let empty_impl_generics = if flow_mode {
@@ -3337,7 +5224,7 @@ impl State {
         let empty_impl_trait_args = if flow_mode {
             quote! { <::inception::False, In, Out #internal_trait_generic_args> }
         } else {
-            quote! { <::inception::False, In #internal_trait_generic_args> }
+            quote! { <::inception::False, In #internal_trait_single_input_args> }
         };
         let merge_var_args = if merge_var_args.is_empty() {
             quote! {}
@@ -3354,13 +5241,19 @@ impl State {
         } else {
             quote! { #nothing_args }
         };
-        let (join_args, join_trait_args) = if is_comparator {
+        let (join_args, join_trait_args) = if let Some(rhs) = &rhs_ty {
+            let operands = join_arg_idents.iter();
+            let join_args = quote! { #(, #operands: &#rhs)* };
+            (join_args.clone(), join_args)
+        } else if is_comparator {
+            let operands = join_arg_idents.iter();
             let join_args = if is_type_style {
-                quote! { #join_arg_idents: #mutref Self }
+                quote! { #(#operands: #mutref Self),* }
             } else {
-                quote! { , #join_arg_idents: #mutref Self }
+                quote! { #(, #operands: #mutref Self)* }
             };
-            (join_args, quote! { , #join_arg_idents: F })
+            let operands = join_arg_idents.iter();
+            (join_args, quote! { #(, #operands: F)* })
         } else if join_extra_args.is_empty() {
             (quote! {}, quote! {})
         } else {
@@ -3397,7 +5290,12 @@ impl State {
         } else {
             quote! { #merge_ret }
         };
-        let flow_assoc_merge_ret_generic = if flow_assoc_borrow_mode {
+        let flow_assoc_merge_ret_generic = if flow_assoc_borrow_mode || gat_mode {
+            quote! { , #flow_assoc_merge_ret_ident }
+        } else {
+            quote! {}
+        };
+        let merge_var_ret_generic = if gat_mode {
             quote! { , #flow_assoc_merge_ret_ident }
         } else {
             quote! {}
@@ -3411,11 +5309,11 @@ impl State {
             match kind {
                 Kind::Ref => quote! {
                     {
-                        let next = <#merge_head_ident as #inner_trait<<#merge_head_ident as ::inception::IsPrimitive<#property>>::Is, In #internal_trait_single_input_args>>::#inner_fn(
+                        let next = <#merge_head_ident as #inner_trait<<#merge_head_ident as ::inception::IsPrimitive<#property>>::Is, In #internal_trait_single_input_args>>::#inner_fn #method_turbofish(
                             #merge_head_arg.access(),
                             #flow_merge_passthrough_args #flow_merge_input_ident,
                         );
-                        <#merge_fields_ident as #inner_trait<::inception::False, #merge_head_out_ty, Out #internal_trait_generic_args>>::#inner_fn(
+                        <#merge_fields_ident as #inner_trait<::inception::False, #merge_head_out_ty, Out #internal_trait_generic_args>>::#inner_fn #method_turbofish(
                             &#merge_fields_arg,
                             #flow_merge_passthrough_args next,
                         )
@@ -3423,11 +5321,11 @@ impl State {
                 },
                 Kind::Mut => quote! {
                     {
-                        let next = <#merge_head_ident as #inner_trait<<#merge_head_ident as ::inception::IsPrimitive<#property>>::Is, In #internal_trait_single_input_args>>::#inner_fn(
+                        let next = <#merge_head_ident as #inner_trait<<#merge_head_ident as ::inception::IsPrimitive<#property>>::Is, In #internal_trait_single_input_args>>::#inner_fn #method_turbofish(
                             #merge_head_arg.access(),
                             #flow_merge_passthrough_args #flow_merge_input_ident,
                         );
-                        <#merge_fields_ident as #inner_trait<::inception::False, #merge_head_out_ty, Out #internal_trait_generic_args>>::#inner_fn(
+                        <#merge_fields_ident as #inner_trait<::inception::False, #merge_head_out_ty, Out #internal_trait_generic_args>>::#inner_fn #method_turbofish(
                             &mut #merge_fields_arg,
                             #flow_merge_passthrough_args next,
                         )
@@ -3442,7 +5340,7 @@ impl State {
             match kind {
                 Kind::Ref => quote! {
                     {
-                        <#join_fields_ident as #inner_trait<::inception::False, In, Out #internal_trait_generic_args>>::#inner_fn(
+                        <#join_fields_ident as #inner_trait<::inception::False, In, Out #internal_trait_generic_args>>::#inner_fn #method_turbofish(
                             &#join_fields_arg,
                             #flow_join_args,
                         )
@@ -3450,7 +5348,7 @@ impl State {
                 },
                 Kind::Mut => quote! {
                     {
-                        <#join_fields_ident as #inner_trait<::inception::False, In, Out #internal_trait_generic_args>>::#inner_fn(
+                        <#join_fields_ident as #inner_trait<::inception::False, In, Out #internal_trait_generic_args>>::#inner_fn #method_turbofish(
                             &mut #join_fields_arg,
                             #flow_join_args,
                         )
@@ -3476,11 +5374,60 @@ impl State {
         } else {
             quote! { , #join_arg_idents }
         };
-        let induce_head_placeholder = format_ident!("Head");
-        let induce_tail_placeholder = format_ident!("Tail");
-        let induce_fields_placeholder = format_ident!("Fields");
-        let induce_in_placeholder = format_ident!("In");
-        let induce_out_placeholder = format_ident!("Out");
+        let hook_items = [
+            (&enter, ENTER_FN_IDENT, "Enter"),
+            (&exit, EXIT_FN_IDENT, "Exit"),
+        ];
+        let (hook_traits, hook_impls): (Vec<_>, Vec<_>) = hook_items
+            .iter()
+            .filter_map(|(hook, name, trait_name)| Some((hook.as_ref()?, name, trait_name)))
+            .map(|(Hook { hook_body, hook_args }, name, trait_name)| {
+                let hook_trait = format_ident!("{trait_name}");
+                let hook_fn = format_ident!("{name}");
+                let decl = quote! {
+                    pub trait #hook_trait {
+                        fn #hook_fn #method_generic_params(#hook_args) #method_where_clause;
+                    }
+                };
+                let imp = quote! {
+                    impl<T> #mod_ident :: #hook_trait for T
+                    where
+                        T: Inception<#property>,
+                    {
+                        fn #hook_fn #method_generic_params(#hook_args) #method_where_clause #hook_body
+                    }
+                };
+                (decl, imp)
+            })
+            .unzip();
+        let (continue_trait, continue_impl) = match &merge_continue {
+            Some(MergeContinue {
+                continue_body,
+                continue_ret,
+                continue_args,
+            }) => (
+                quote! {
+                    pub trait MergeContinue {
+                        fn merge_continue #method_generic_params(#continue_args) #continue_ret #method_where_clause;
+                    }
+                },
+                quote! {
+                    impl<T> #mod_ident :: MergeContinue for T {
+                        fn merge_continue #method_generic_params(#continue_args) #continue_ret #method_where_clause #continue_body
+                    }
+                },
+            ),
+            None => (quote! {}, quote! {}),
+        };
+        let [enter_call, exit_call] = hook_items.map(|(hook, name, trait_name)| {
+            let hook_trait = format_ident!("{trait_name}");
+            let hook_fn = format_ident!("{name}");
+            hook.as_ref().map(|_| {
+                quote! {
+                    <Self as #mod_ident :: #hook_trait>::#hook_fn #method_turbofish(<Self as ::inception::DataType>::NAME #join_arg_idents);
+                }
+            })
+        });
         let merge_tail_ty = if needs_named_ret_lifetime {
             quote! { #wrapper<#ret_liferef F> }
         } else {
@@ -3496,8 +5443,73 @@ impl State {
         } else {
             join_wrapper_fields_ty.clone()
         };
+        // Per-phase `where { ... }` bounds of induced associated types also bound the behavior:
+        // `Head` is the head field, `Tail` the remaining field list and `Fields` the `TyFields`.
+        let phase_where_preds =
+            |phase: fn(&InduceSpec) -> &InducePhaseSpec,
+             replacements: &[(&Ident, proc_macro2::TokenStream)]| {
+                let replacements = replacements
+                    .iter()
+                    .filter_map(|(target, ty)| {
+                        Some((*target, syn::parse2::<Type>(ty.clone()).ok()?))
+                    })
+                    .collect::<Vec<_>>();
+                let replacements = replacements
+                    .iter()
+                    .map(|(target, ty)| (*target, ty))
+                    .collect::<Vec<_>>();
+                assoc_types
+                    .iter()
+                    .filter_map(|t| t.induce.as_ref())
+                    .flat_map(|induce| {
+                        substitute_where_preds(&phase(induce).where_preds, &replacements)
+                    })
+                    .collect::<Vec<_>>()
+            };
+        let phase_out_ty = if flow_mode {
+            quote! { Out }
+        } else {
+            quote! { In }
+        };
+        let base_where_preds = phase_where_preds(
+            |induce| &induce.base,
+            &[
+                (&induce_in_placeholder, quote! { In }),
+                (&induce_out_placeholder, phase_out_ty.clone()),
+            ],
+        );
+        let merge_where_preds = phase_where_preds(
+            |induce| &induce.merge,
+            &[
+                (&induce_head_placeholder, quote! { #merge_head_ident }),
+                (&induce_tail_placeholder, quote! { F }),
+                (&induce_in_placeholder, quote! { In }),
+                (&induce_out_placeholder, phase_out_ty.clone()),
+            ],
+        );
+        let merge_var_where_preds = phase_where_preds(
+            |induce| &induce.merge_variant,
+            &[
+                (&induce_head_placeholder, quote! { #merge_var_head_ident }),
+                (&induce_tail_placeholder, quote! { F }),
+                (&induce_in_placeholder, quote! { In }),
+                (&induce_out_placeholder, phase_out_ty.clone()),
+            ],
+        );
+        let join_where_preds = phase_where_preds(
+            |induce| &induce.join,
+            &[
+                (
+                    &induce_fields_placeholder,
+                    quote! { <T as Inception<#property>>::TyFields },
+                ),
+                (&induce_in_placeholder, quote! { In }),
+                (&induce_out_placeholder, phase_out_ty.clone()),
+            ],
+        );
         let induced_assoc_helpers = assoc_types
             .iter()
+            .filter(|t| !is_gat_ret(t))
             .filter_map(|t| {
                 let induce = t.induce.as_ref()?;
                 let assoc_ident = &t.item.ident;
@@ -3567,6 +5579,11 @@ impl State {
                     (Some(_), None) => quote! { <True, In #internal_trait_single_input_args> },
                     (None, _) => quote! { <True, In #internal_trait_generic_args> },
                 };
+                let helper_primitive_trait = if flow_input_from_assoc {
+                    quote! { super::#trait_ident #trait_generic_args }
+                } else {
+                    trait_bound_with_in.clone()
+                };
                 let helper_false_trait_args = if flow_mode {
                     quote! { <::inception::False, In, Out #internal_trait_generic_args> }
                 } else {
@@ -3577,28 +5594,106 @@ impl State {
                 } else {
                     quote! { <In #internal_trait_impl_generic_defs> }
                 };
-                let helper_head_trait_args = if flow_mode {
+                // Induced types only depend on the field types, so receiver traits induct over the
+                // type-level fields rather than the borrowed or owned ones. Along a `signature(...)`,
+                // each head is given the input and its tail the head's output.
+                let type_level = !matches!(kind, Kind::Ty);
+                let type_level_head_args = |head: &Ident| {
+                    quote! { <<#head as ::inception::IsPrimitive<#property>>::Is, In #internal_trait_single_input_args> }
+                };
+                let helper_head_trait_args = if type_level && flow_mode {
+                    type_level_head_args(&merge_head_ident)
+                } else if flow_mode {
                     quote! { <<#merge_head_ident as ::inception::IsPrimitive<#property>>::Is, In, Out #internal_trait_generic_args> }
                 } else {
                     quote! { <<#merge_head_ident as ::inception::IsPrimitive<#property>>::Is, In #internal_trait_generic_args> }
                 };
-                let helper_merge_var_head_trait_args = if flow_mode {
+                let helper_merge_var_head_trait_args = if type_level && flow_mode {
+                    type_level_head_args(&merge_var_head_ident)
+                } else if flow_mode {
                     quote! { <<#merge_var_head_ident as ::inception::IsPrimitive<#property>>::Is, In, Out #internal_trait_generic_args> }
                 } else {
                     quote! { <<#merge_var_head_ident as ::inception::IsPrimitive<#property>>::Is, In #internal_trait_generic_args> }
                 };
+                let head_out = |head: &Ident| match output_assoc_ident.as_ref() {
+                    Some(out) if flow_input_from_assoc => quote! { <#head as #trait_path_for_ufcs>::#out },
+                    Some(out) => quote! { <#head as #trait_bound_with_in>::#out },
+                    None => quote! { In },
+                };
+                let (helper_tail_trait_args, helper_merge_var_tail_trait_args, helper_head_out_bound, helper_merge_var_head_out_bound) =
+                    if type_level && flow_mode {
+                        let merge_out = head_out(&merge_head_ident);
+                        let merge_var_out = head_out(&merge_var_head_ident);
+                        (
+                            quote! { <::inception::False, #merge_out, Out #internal_trait_generic_args> },
+                            quote! { <::inception::False, #merge_var_out, Out #internal_trait_generic_args> },
+                            quote! { #merge_head_ident: #trait_bound_with_in, },
+                            quote! { #merge_var_head_ident: #trait_bound_with_in, },
+                        )
+                    } else {
+                        (helper_false_trait_args.clone(), helper_false_trait_args.clone(), quote! {}, quote! {})
+                    };
+                let helper_lifetime_decl = if type_level {
+                    quote! {}
+                } else {
+                    quote! { #ret_lifetime_decl }
+                };
+                let helper_merge_ret_generic = if type_level {
+                    quote! {}
+                } else {
+                    flow_assoc_merge_ret_generic.clone()
+                };
                 let helper_merge_impl_generics = if flow_mode {
-                    quote! { <#ret_lifetime_decl #merge_head_ident, S, const IDX: usize, F, In, Out #flow_assoc_merge_ret_generic #internal_trait_impl_generic_defs> }
+                    quote! { <#helper_lifetime_decl #merge_head_ident, S, const IDX: usize, F, In, Out #helper_merge_ret_generic #internal_trait_impl_generic_defs> }
                 } else {
-                    quote! { <#ret_lifetime_decl #merge_head_ident, S, const IDX: usize, F, In #internal_trait_impl_generic_defs> }
+                    quote! { <#helper_lifetime_decl #merge_head_ident, S, const IDX: usize, F, In #internal_trait_impl_generic_defs> }
                 };
                 let helper_merge_var_impl_generics = if flow_mode {
-                    quote! { <#ret_lifetime_decl #merge_var_head_ident, S, const VAR_IDX: usize, const IDX: usize, F, In, Out #internal_trait_impl_generic_defs> }
+                    quote! { <#helper_lifetime_decl #merge_var_head_ident, S, const VAR_IDX: usize, const IDX: usize, F, In, Out #internal_trait_impl_generic_defs> }
+                } else {
+                    quote! { <#helper_lifetime_decl #merge_var_head_ident, S, const VAR_IDX: usize, const IDX: usize, F, In #internal_trait_impl_generic_defs> }
+                };
+                let ty_field = Kind::Ty.field();
+                let ty_var_field = Kind::Ty.var_field();
+                let (
+                    merge_tail_ty,
+                    merge_var_tail_ty,
+                    join_fields_ty,
+                    helper_empty_self_ty,
+                    helper_merge_self_ty,
+                    helper_merge_var_self_ty,
+                    helper_merge_split_bound,
+                    helper_merge_var_split_bound,
+                ) = if type_level {
+                    (
+                        quote! { #wrapper<F> },
+                        quote! { #wrapper<F> },
+                        quote! { #wrapper<<T as Inception<#property>>::TyFields> },
+                        quote! { #wrapper<List<()>> },
+                        quote! { #wrapper<List<(#ty_field<#merge_head_ident, S, IDX>, F)>> },
+                        quote! { #wrapper<List<(#ty_var_field<#merge_var_head_ident, S, VAR_IDX, IDX>, F)>> },
+                        quote! {},
+                        quote! {},
+                    )
+                } else {
+                    (
+                        merge_tail_ty.clone(),
+                        merge_var_tail_ty.clone(),
+                        join_fields_ty.clone(),
+                        quote! { #wrapper<#liferefelide List<()>> },
+                        merge_inductive_self_ty.clone(),
+                        merge_var_inductive_self_ty.clone(),
+                        merge_split_bound.clone(),
+                        merge_var_split_bound.clone(),
+                    )
+                };
+                let helper_join_ret_generic = if type_level {
+                    quote! {}
                 } else {
-                    quote! { <#ret_lifetime_decl #merge_var_head_ident, S, const VAR_IDX: usize, const IDX: usize, F, In #internal_trait_impl_generic_defs> }
+                    flow_assoc_join_ret_generic.clone()
                 };
                 let helper_join_impl_generics = if flow_mode {
-                    quote! { <T, In, Out #flow_assoc_join_ret_generic #internal_trait_impl_generic_defs> }
+                    quote! { <T, In, Out #helper_join_ret_generic #internal_trait_impl_generic_defs> }
                 } else {
                     quote! { <T, In #internal_trait_impl_generic_defs> }
                 };
@@ -3613,10 +5708,10 @@ impl State {
                     quote! { In }
                 };
                 let tail_assoc_ty = quote! {
-                    <#merge_tail_ty as #helper_ident #helper_false_trait_args>::Ret #assoc_proj_generics
+                    <#merge_tail_ty as #helper_ident #helper_tail_trait_args>::Ret #assoc_proj_generics
                 };
                 let merge_var_tail_assoc_ty = quote! {
-                    <#merge_var_tail_ty as #helper_ident #helper_false_trait_args>::Ret #assoc_proj_generics
+                    <#merge_var_tail_ty as #helper_ident #helper_merge_var_tail_trait_args>::Ret #assoc_proj_generics
                 };
                 let fields_assoc_ty = quote! {
                     <#join_fields_ty as #helper_ident #helper_false_trait_args>::Ret #assoc_proj_generics
@@ -3646,7 +5741,7 @@ impl State {
                     &induce_tail_placeholder,
                     &helper_ident,
                     &merge_tail_self_ty,
-                    &helper_false_trait_args,
+                    &helper_tail_trait_args,
                 );
                 let mut merge_ty = quote! { #merge_ty_src };
                 merge_ty = substitute_ret_ident(&merge_ty, &induce_head_placeholder, &quote! { #merge_head_ident });
@@ -3670,7 +5765,7 @@ impl State {
                     &induce_tail_placeholder,
                     &helper_ident,
                     &merge_var_tail_self_ty,
-                    &helper_false_trait_args,
+                    &helper_merge_var_tail_trait_args,
                 );
                 let mut merge_var_ty = quote! { #merge_var_ty_src };
                 merge_var_ty = substitute_ret_ident(&merge_var_ty, &induce_head_placeholder, &quote! { #merge_var_head_ident });
@@ -3695,72 +5790,459 @@ impl State {
                     pub trait #helper_ident<P: TruthValue = <Self as IsPrimitive<super::#property_ident>>::Is, In = (), Out = In #internal_trait_decl_generic_defs> {
                         type Ret #assoc_decl_generics #assoc_where_clause;
                     }
-
-                    impl #helper_primitive_impl_generics #helper_ident #helper_primitive_trait_args for T
-                    where
-                        T: #trait_bound_with_in + IsPrimitive<super::#property_ident, Is = True>,
-                    {
-                        type Ret #assoc_impl_generics = <T as super::#trait_ident #trait_generic_args>::#assoc_ident #assoc_proj_generics #assoc_where_clause;
+
+                    impl #helper_primitive_impl_generics #helper_ident #helper_primitive_trait_args for T
+                    where
+                        T: #trait_bound_with_in + IsPrimitive<super::#property_ident, Is = True>,
+                    {
+                        type Ret #assoc_impl_generics = <T as #helper_primitive_trait>::#assoc_ident #assoc_proj_generics #assoc_where_clause;
+                    }
+
+                    impl #helper_empty_impl_generics #helper_ident #helper_false_trait_args for #helper_empty_self_ty
+                    where
+                        #(#base_where_preds,)*
+                    {
+                        type Ret #assoc_impl_generics = #base_ty #assoc_where_clause;
+                    }
+
+                    impl #helper_merge_impl_generics
+                        #helper_ident #helper_false_trait_args for #helper_merge_self_ty
+                    where
+                        S: FieldsMeta,
+                        #merge_head_ident: ::inception::IsPrimitive<#property>,
+                        #merge_head_ident: #helper_ident #helper_head_trait_args,
+                        #helper_head_out_bound
+                        #merge_tail_ty: #helper_ident #helper_tail_trait_args,
+                        F: Fields #phantom_bound,
+                        <F as Fields>::Owned: Fields,
+                        #helper_merge_split_bound
+                        #(#merge_where_preds,)*
+                    {
+                        type Ret #assoc_impl_generics = #merge_ty #assoc_where_clause;
+                    }
+
+                    impl #helper_merge_var_impl_generics
+                        #helper_ident #helper_false_trait_args for #helper_merge_var_self_ty
+                    where
+                        S: FieldsMeta + EnumMeta + VariantOffset<VAR_IDX>,
+                        #merge_var_head_ident: ::inception::IsPrimitive<#property>,
+                        #merge_var_head_ident: #helper_ident #helper_merge_var_head_trait_args,
+                        #helper_merge_var_head_out_bound
+                        #merge_var_tail_ty: #helper_ident #helper_merge_var_tail_trait_args,
+                        F: Fields #phantom_bound,
+                        <F as Fields>::Owned: Fields,
+                        #helper_merge_var_split_bound
+                        #(#merge_var_where_preds,)*
+                    {
+                        type Ret #assoc_impl_generics = #merge_var_ty #assoc_where_clause;
+                    }
+
+                    impl #helper_join_impl_generics
+                        #helper_ident #helper_false_trait_args for T
+                    where
+                        T: Inception<#property> + Meta,
+                        #join_fields_ty: #helper_ident #helper_false_trait_args,
+                        #(#join_where_preds,)*
+                    {
+                        type Ret #assoc_impl_generics = #join_ty #assoc_where_clause;
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut merge_call = quote! {
+            <Self as #mod_ident :: MergeField #merge_call_trait_args>::merge_field #method_turbofish(l, r #merge_arg_idents) #dot_await
+        };
+        let mut merge_var_call = quote! {
+            <Self as #mod_ident :: MergeVariantField #merge_variant_call_trait_args>::merge_variant_field #method_turbofish(l, r #merge_var_arg_idents) #dot_await
+        };
+        let mut merge_fallible_bound = quote! {};
+        let mut merge_var_fallible_bound = quote! {};
+        if fallible {
+            // The head is evaluated here, so that its `Err` is returned before the step can visit
+            // the tail. The step then sees the head's `Ok` value.
+            let eval_head = |head_ident: &Ident, head: proc_macro2::TokenStream| {
+                quote! {
+                    <#head_ident as #inner_trait>::#inner_fn #method_turbofish(#head #fn_arg_idents) #dot_await
+                }
+            };
+            merge_fallible_bound = quote! {
+                #merge_head_ident: #inner_trait<Ret = #merge_ret_inductive>,
+            };
+            merge_var_fallible_bound = quote! {
+                #merge_var_head_ident: #inner_trait<Ret = #merge_var_ret_inductive>,
+            };
+            let head = match kind {
+                Kind::Ty => quote! {},
+                _ => quote! { l.access(), },
+            };
+            let eval = eval_head(&merge_head_ident, head);
+            merge_call = quote! {
+                match #eval {
+                    Ok(head) => {
+                        let l = ::inception::OwnedField::<_, S, IDX>::new(head);
+                        #merge_call
+                    }
+                    Err(e) => Err(e),
+                }
+            };
+            let (present, header) = match kind {
+                Kind::Ty => (
+                    eval_head(&merge_var_head_ident, quote! {}),
+                    eval_head(&merge_var_head_ident, quote! {}),
+                ),
+                _ => (
+                    eval_head(&merge_var_head_ident, quote! { head, }),
+                    eval_head(&merge_var_head_ident, quote! { head.access(), }),
+                ),
+            };
+            let present_pat = match kind {
+                Kind::Ty => quote! { #var_field::Ty(_) },
+                _ => quote! { Ok(head) },
+            };
+            let header_pat = match kind {
+                Kind::Ty => quote! { #var_field::Header(_) },
+                _ => {
+                    let enum_err_ident = kind.enum_err_ident();
+                    quote! { Err(#enum_err_ident::Header(head)) }
+                }
+            };
+            let scrutinee = match kind {
+                Kind::Ty => quote! { l },
+                _ => quote! { l.try_access() },
+            };
+            merge_var_call = quote! {
+                match match #scrutinee {
+                    #present_pat => #present.map(::inception::VarOwnedField::<_, S, VAR_IDX, IDX>::new),
+                    #header_pat => #header.map(|head| {
+                        ::inception::VarOwnedField::<_, S, VAR_IDX, IDX>::Header(::inception::OwnedField::new(head))
+                    }),
+                    #[allow(unreachable_patterns)]
+                    _ => Ok(::inception::VarOwnedField::<_, S, VAR_IDX, IDX>::Empty(::inception::Empty::new())),
+                } {
+                    Ok(l) => #merge_var_call,
+                    Err(e) => Err(e),
+                }
+            };
+            // Errors which were not located by a nested field belong to the head of this merge.
+            merge_call = quote! {
+                ::inception::field::locate(#merge_call, || ::inception::FieldLocation {
+                    ty: <S as ::inception::DataType>::NAME,
+                    variant: None,
+                    index: IDX,
+                    name: ::inception::meta::field_name(<S as ::inception::FieldsMeta>::NAMES, 0, IDX),
+                })
+            };
+            merge_var_call = quote! {
+                ::inception::field::locate(#merge_var_call, || ::inception::FieldLocation {
+                    ty: <S as ::inception::DataType>::NAME,
+                    variant: Some(<S as ::inception::EnumMeta>::VARIANT_NAMES[VAR_IDX]),
+                    index: IDX,
+                    name: ::inception::meta::field_name(<S as ::inception::FieldsMeta>::NAMES, VAR_IDX, IDX),
+                })
+            };
+        }
+        if let Some(path) = &path_arg {
+            merge_call = quote! {
+                {
+                    let outer = #path.replace(Some(::inception::FieldLocation {
+                        ty: <S as ::inception::DataType>::NAME,
+                        variant: None,
+                        index: IDX,
+                        name: ::inception::meta::field_name(<S as ::inception::FieldsMeta>::NAMES, 0, IDX),
+                    }));
+                    let merged = #merge_call;
+                    #path.replace(outer);
+                    merged
+                }
+            };
+            merge_var_call = quote! {
+                {
+                    let outer = #path.replace(Some(::inception::FieldLocation {
+                        ty: <S as ::inception::DataType>::NAME,
+                        variant: Some(<S as ::inception::EnumMeta>::VARIANT_NAMES[VAR_IDX]),
+                        index: IDX,
+                        name: ::inception::meta::field_name(<S as ::inception::FieldsMeta>::NAMES, VAR_IDX, IDX),
+                    }));
+                    let merged = #merge_var_call;
+                    #path.replace(outer);
+                    merged
+                }
+            };
+        }
+        let (path_push, path_pop) = match &join_path_arg {
+            Some(path) => (quote! { #path.push(); }, quote! { #path.pop(); }),
+            None => (quote! {}, quote! {}),
+        };
+        let mut merge_short_circuit_bound = quote! {};
+        let mut merge_var_short_circuit_bound = quote! {};
+        if short_circuit {
+            merge_short_circuit_bound = quote! {
+                #split_for_3 #merge_split_right_ty:
+                    #inner_trait<::inception::False, #merge_head_out_ty, Out #internal_trait_generic_args, Ret = #merge_ret_inductive>,
+            };
+            merge_var_short_circuit_bound = quote! {
+                #split_for_3 #merge_var_split_right_ty:
+                    #inner_trait<::inception::False, #merge_var_head_out_ty, Out #internal_trait_generic_args, Ret = #merge_var_ret_inductive>,
+            };
+            // The steps only decide on their head field, the tail is visited here unless they break.
+            let other_tails = (0..comparator_arity - 1)
+                .map(|i| format_ident!("tail{}", i + 2))
+                .collect::<Vec<_>>();
+            let tail_args = if is_comparator {
+                let rest = fn_arg_idents.iter().skip(comparator_arity - 1);
+                quote! { #(&#wrapper(#other_tails)),* #(, #rest)* }
+            } else {
+                quote! { #fn_arg_idents }
+            };
+            let tail_call = |head_out_ty: &proc_macro2::TokenStream,
+                             right_ty: &proc_macro2::TokenStream| {
+                let tail_trait = quote! {
+                    #inner_trait<::inception::False, #head_out_ty, Out #internal_trait_generic_args>
+                };
+                match kind {
+                    Kind::Ty => quote! {
+                        <#right_ty as #tail_trait>::#inner_fn #method_turbofish(#tail_args) #dot_await
+                    },
+                    _ => quote! {
+                        <_ as #tail_trait>::#inner_fn #method_turbofish(&#wrapper(tail), #tail_args) #dot_await
+                    },
+                }
+            };
+            let split_other = if is_comparator {
+                quote! {
+                    #(let (_, #other_tails) = #operand_dispatchers #split_fn_ident(#split_fn_receiver);)*
+                }
+            } else {
+                quote! {}
+            };
+            // The `Continue` value of the head is combined with the tail's by `merge_continue`.
+            let head_pat = match &merge_continue {
+                Some(_) => quote! { head },
+                None => quote! { _ },
+            };
+            let combine = |tail_call: proc_macro2::TokenStream| match &merge_continue {
+                Some(_) => quote! {
+                    match #tail_call {
+                        ::core::ops::ControlFlow::Continue(rest) => {
+                            <Self as #mod_ident :: MergeContinue>::merge_continue #method_turbofish(head, rest)
+                        }
+                        flow => flow,
+                    }
+                },
+                None => tail_call,
+            };
+            let merge_tail_call = combine(tail_call(&merge_head_out_ty, &merge_split_right_ty));
+            let merge_var_tail_call =
+                combine(tail_call(&merge_var_head_out_ty, &merge_var_split_right_ty));
+            merge_call = quote! {
+                match #merge_call {
+                    ::core::ops::ControlFlow::Continue(#head_pat) => {
+                        let (_, tail) = #dispatcher #split_fn_ident(#split_fn_receiver);
+                        #split_other
+                        #merge_tail_call
                     }
-
-                    impl #helper_empty_impl_generics #helper_ident #helper_false_trait_args for #wrapper<#liferefelide List<()>> {
-                        type Ret #assoc_impl_generics = #base_ty #assoc_where_clause;
+                    flow => flow,
+                }
+            };
+            merge_var_call = quote! {
+                match #merge_var_call {
+                    ::core::ops::ControlFlow::Continue(#head_pat) => {
+                        let (_, tail) = #dispatcher #split_fn_ident(#split_fn_receiver);
+                        #split_other
+                        #merge_var_tail_call
                     }
+                    flow => flow,
+                }
+            };
+        }
+        // An omitted `merge_variant_field` only merges the fields of the active variant and goes
+        // straight on to the tail past headers and empty placeholders.
+        let mut merge_var_access_bound = quote! {};
+        if merge_var_default {
+            let present = kind.present_variant();
+            let tail_call = match kind {
+                Kind::Ty => quote! {},
+                _ => quote! {
+                    <_ as #inner_trait<::inception::False, #merge_var_head_out_ty, Out>>::#inner_fn #method_turbofish(#mutref r, #fn_arg_idents) #dot_await
+                },
+            };
+            merge_var_call = quote! {
+                if matches!(l, #var_field::#present(_)) {
+                    #merge_var_call
+                } else {
+                    #tail_call
+                }
+            };
+            merge_var_access_bound = match &fallible_ok {
+                Some(ok) => quote! { + Access<Out = #ok> },
+                None => kind.access_bound(false, &merge_var_head_ident),
+            };
+            if !short_circuit {
+                merge_var_short_circuit_bound = quote! {
+                    #split_for_3 #merge_var_split_right_ty:
+                        #inner_trait<::inception::False, #merge_var_head_out_ty, Out, Ret = #merge_var_ret_inductive>,
+                };
+            }
+        }
 
-                    impl #helper_merge_impl_generics
-                        #helper_ident #helper_false_trait_args for #merge_inductive_self_ty
+        // `merge_variant` sees the fields of enums grouped by variant, see `Kind::grouped_fields_fn`.
+        let (merge_variant_trait, merge_variant_impls) = match merge_variant {
+            Some(MergeVariant {
+                merge_variant_body,
+                merge_variant_ret,
+                merge_variant_fields_ident,
+                merge_variant_rest_ident,
+                merge_variant_head_arg,
+                merge_variant_fields_arg,
+                merge_variant_rest_arg,
+                merge_variant_fields_bounds,
+                merge_variant_rest_bounds,
+                merge_variant_args,
+                merge_variant_arg_idents,
+            }) => {
+                let merge_variant_fields_bounds = merge_variant_fields_bounds
+                    .use_inner_trait(&trait_ident, &inductive_ident, &mod_ident, None, None)
+                    .into_tokens();
+                let merge_variant_rest_bounds = merge_variant_rest_bounds
+                    .use_inner_trait(&trait_ident, &inductive_ident, &mod_ident, None, None)
+                    .into_tokens();
+                let merge_variant_args = if merge_variant_args.is_empty() {
+                    quote! {}
+                } else {
+                    quote! { , #merge_variant_args }
+                };
+                let merge_variant_arg_idents = if merge_variant_arg_idents.is_empty() {
+                    quote! {}
+                } else {
+                    quote! { , #merge_variant_arg_idents }
+                };
+                let head_ident = format_ident!("H");
+                let try_access_bound = kind.access_bound(true, &head_ident);
+                let split_variant = kind.split_variant();
+                let variant_phantom_bounds = match kind {
+                    Kind::Ty => quote! { F: Phantom, Rest: Phantom, },
+                    _ => quote! {},
+                };
+                let variants_ty = |life: &proc_macro2::TokenStream| {
+                    quote! {
+                        #wrapper<#liferefelide ::inception::Variants<List<(List<(#var_field<#life H, S, VAR_IDX, IDX>, F)>, Rest)>>>
+                    }
+                };
+                let self_ty = variants_ty(&lifepunct1);
+                let inductive_self_ty = variants_ty(&lifepunctelide);
+                let trait_def = quote! {
+                    pub trait MergeVariant<L, V, R, In = ()> {
+                        type Ret;
+                        fn merge_variant #method_generic_params(l: L, v: V, r: R #merge_variant_args) -> Self::Ret #method_where_clause;
+                    }
+                };
+                let impls = quote! {
+                    impl<#lifepunct1 H, S, const VAR_IDX: usize, const IDX: usize, F, Rest, L, #merge_variant_fields_ident, #merge_variant_rest_ident, In>
+                        #mod_ident :: MergeVariant<L, #merge_variant_fields_ident, #merge_variant_rest_ident, In> for #self_ty
                     where
-                        S: FieldsMeta,
-                        #merge_head_ident: ::inception::IsPrimitive<#property>,
-                        #merge_head_ident: #helper_ident #helper_head_trait_args,
-                        #merge_tail_ty: #helper_ident #helper_false_trait_args,
-                        F: Fields #phantom_bound,
-                        <F as Fields>::Owned: Fields,
-                        #merge_split_bound
+                        S: FieldsMeta + EnumMeta + VariantOffset<VAR_IDX>,
+                        L: Field<Source = S> + VarField #try_access_bound,
+                        #merge_variant_fields_ident: #inner_trait #merge_variant_fields_bounds + ::inception::IsPrimitive<#property>,
+                        #merge_variant_rest_ident: #inner_trait #merge_variant_rest_bounds + ::inception::IsPrimitive<#property>,
                     {
-                        type Ret #assoc_impl_generics = #merge_ty #assoc_where_clause;
+                        type Ret = #merge_variant_ret;
+                        fn merge_variant #method_generic_params(#mutability #merge_variant_head_arg: L, #mutability #merge_variant_fields_arg: #merge_variant_fields_ident, #mutability #merge_variant_rest_arg: #merge_variant_rest_ident #merge_variant_args) -> Self::Ret #method_where_clause {
+                            #merge_variant_body
+                        }
                     }
 
-                    impl #helper_merge_var_impl_generics
-                        #helper_ident #helper_false_trait_args for #merge_var_inductive_self_ty
+                    impl #empty_impl_generics #inner_trait #empty_impl_trait_args for #wrapper<#liferefelide ::inception::Variants<List<()>>>
                     where
-                        S: FieldsMeta + EnumMeta + VariantOffset<VAR_IDX>,
-                        #merge_var_head_ident: ::inception::IsPrimitive<#property>,
-                        #merge_var_head_ident: #helper_ident #helper_merge_var_head_trait_args,
-                        #merge_var_tail_ty: #helper_ident #helper_false_trait_args,
-                        F: Fields #phantom_bound,
-                        <F as Fields>::Owned: Fields,
-                        #merge_var_split_bound
+                        #(#base_where_preds,)*
                     {
-                        type Ret #assoc_impl_generics = #merge_var_ty #assoc_where_clause;
+                        type Property = #property;
+                        type InTy = <Self as #mod_ident :: Nothing<In>>::InTy;
+                        type OutTy = <Self as #mod_ident :: Nothing<In>>::OutTy;
+                        type Ret = #nothing_ret;
+                        #[allow(unused)]
+                        fn #inner_fn #method_generic_params(#mutref #receiver #inner_fn_args) -> Self::Ret #method_where_clause {
+                            <Self as #mod_ident :: Nothing<In>>::nothing #method_turbofish(#nothing_arg_idents)
+                        }
                     }
 
-                    impl #helper_join_impl_generics
-                        #helper_ident #helper_false_trait_args for T
+                    impl<H, S, const VAR_IDX: usize, const IDX: usize, F, Rest, In, Out> #inner_trait<::inception::False, In, Out>
+                        for #inductive_self_ty
                     where
-                        T: Inception<#property> + Meta,
-                        #join_fields_ty: #helper_ident #helper_false_trait_args,
+                        S: FieldsMeta + EnumMeta + VariantOffset<VAR_IDX>,
+                        #variant_phantom_bounds
+                        for<#life2> #wrapper<#liferef2 F>: #inner_trait #merge_variant_fields_bounds + ::inception::IsPrimitive<#property>,
+                        for<#life2> #wrapper<#liferef2 Rest>: #inner_trait #merge_variant_rest_bounds + ::inception::IsPrimitive<#property>,
                     {
-                        type Ret #assoc_impl_generics = #join_ty #assoc_where_clause;
+                        type Property = #property;
+                        type InTy = In;
+                        type OutTy = Out;
+                        type Ret = #merge_variant_ret;
+                        fn #inner_fn #method_generic_params(#mutref #receiver #inner_fn_args) -> Self::Ret #method_where_clause {
+                            #split_variant
+                            <Self as #mod_ident :: MergeVariant<_, _, _, In>>::merge_variant #method_turbofish(l, #wrapper(v), #wrapper(r) #merge_variant_arg_idents)
+                        }
                     }
-                })
-            })
-            .collect::<Vec<_>>();
-
-        let expanded = quote! {
-            pub struct #property_ident;
-            #vis trait #trait_ident #trait_generic_params #trait_supertrait_clause #trait_where_clause {
-                #(#assoc_trait_items)*
-                fn #fn_ident(#mutref #receiver #fn_args) -> #fn_ret_public;
+                };
+                (trait_def, impls)
             }
-
-            mod #mod_ident {
-                use inception::{Wrapper, TruthValue, IsPrimitive, meta::Metadata, True, False};
-                use super::*;
-
-                impl ::inception::Property for super::#property_ident {}
-                #compat_impl
-
+            None => (quote! {}, quote! {}),
+        };
+        let trait_head = quote! {
+            #vis trait #trait_ident #trait_generic_params #trait_supertrait_clause #trait_where_clause
+        };
+        let trait_fn = quote! {
+            #asyncness fn #fn_ident #method_generic_params(#mutref #receiver #fn_args) -> #fn_ret_public #method_where_clause;
+        };
+        let property_impls = quote! {
+            impl ::inception::Property for super::#property_ident {}
+            #compat_impl
+        };
+        let primitive_impl = if gat_mode {
+            quote! {}
+        } else {
+            quote! {
+                impl #primitive_impl_generics #inductive_ident #primitive_impl_trait_args for T
+                where
+                    T: #trait_bound_with_in + IsPrimitive<super::#property_ident, Is = True>,
+                {
+                    type Property = super::#property_ident;
+                    type InTy = In;
+                    type OutTy = #primitive_out_ty;
+                    type Ret = #primitive_ret;
+                    #asyncness fn #inner_fn #method_generic_params(#mutref #receiver #inner_fn_args) -> Self::Ret #method_where_clause {
+                        #primitive_dispatch_body
+                    }
+                }
+            }
+        };
+        // Behaviors returning an induced GAT only induct over borrowed fields, and borrow them
+        // from `self` for the lifetime of the GAT.
+        let join_fields = if gat_mode {
+            quote! {
+                pub trait JoinFields<'a> {
+                    type Ret;
+                    fn join_fields(&'a self) -> Self::Ret;
+                }
+                impl<'a, T, Ret> JoinFields<'a> for T
+                where
+                    T: Inception<super::#property_ident>,
+                    for<'w> Wrap<&'w <T as Inception<super::#property_ident>>::#fields_ident<'a>>:
+                        #inductive_ident<False, Ret = Ret>,
+                    #(#join_where_preds,)*
+                {
+                    type Ret = Ret;
+                    fn join_fields(&'a self) -> Self::Ret {
+                        let fields = self.fields();
+                        let f = Wrap(&fields);
+                        <T as Join<_>>::join(f)
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+        let module_body = quote! {
                 pub struct Wrap<T>(pub T);
                 impl<T> Wrapper for Wrap<T> {
                     type Content = T;
@@ -3772,71 +6254,97 @@ impl State {
                 impl<T> IsPrimitive<super::#property_ident> for Wrap<T> {
                     type Is = False;
                 }
+                #allow_async_fn_in_trait
                 pub trait #inductive_ident<P: TruthValue = <Self as IsPrimitive<super::#property_ident>>::Is, In = (), Out = In #internal_trait_decl_generic_defs> {
                     type Property: ::inception::Property;
                     type InTy;
                     type OutTy;
                     type Ret;
-                    fn #inner_fn(#mutref #receiver #inner_fn_args) -> Self::Ret;
-                }
-                impl #primitive_impl_generics #inductive_ident #primitive_impl_trait_args for T
-                where
-                    T: #trait_bound_with_in + IsPrimitive<super::#property_ident, Is = True>,
-                {
-                    type Property = super::#property_ident;
-                    type InTy = In;
-                    type OutTy = #primitive_out_ty;
-                    type Ret = #primitive_ret;
-                    fn #inner_fn(#mutref #receiver #inner_fn_args) -> Self::Ret {
-                        #primitive_dispatch_body
-                    }
+                    #asyncness fn #inner_fn #method_generic_params(#mutref #receiver #inner_fn_args) -> Self::Ret #method_where_clause;
                 }
+                #primitive_impl
 
+                #allow_async_fn_in_trait
                 pub trait Nothing<In = () #internal_trait_decl_generic_defs> {
                     type InTy;
                     type OutTy;
                     type Ret;
-                    fn nothing(#nothing_args) -> Self::Ret;
+                    #asyncness fn nothing #method_generic_params(#nothing_args) -> Self::Ret #method_where_clause;
                 }
-                pub trait MergeField<L, R, In = (), Out = In, Extra = () #internal_trait_decl_generic_defs> {
+                #allow_async_fn_in_trait
+                pub trait MergeField<L, R, In = (), Out = In, #merge_extra_param = () #internal_trait_decl_generic_defs> {
                     type InTy;
                     type OutTy;
                     type Ret;
-                    fn merge_field(l: L, r: R #merge_args) -> Self::Ret;
+                    #asyncness fn merge_field #method_generic_params(l: L, r: R #merge_args) -> Self::Ret #method_where_clause;
                 }
-                pub trait MergeVariantField<L, R, In = (), Out = In, Extra = () #internal_trait_decl_generic_defs> {
+                #allow_async_fn_in_trait
+                pub trait MergeVariantField<L, R, In = (), Out = In, #merge_extra_param = () #internal_trait_decl_generic_defs> {
                     type InTy;
                     type OutTy;
                     type Ret;
-                    fn merge_variant_field(l: L, r: R #merge_var_args) -> Self::Ret;
+                    #asyncness fn merge_variant_field #method_generic_params(l: L, r: R #merge_var_args) -> Self::Ret #method_where_clause;
                 }
+                #merge_variant_trait
+                #allow_async_fn_in_trait
                 pub trait Join<F, In = (), Out = In #internal_trait_decl_generic_defs> {
                     type InTy;
                     type OutTy;
                     type Ret;
-                    fn join(fields: F #join_trait_args) -> Self::Ret;
+                    #asyncness fn join #method_generic_params(fields: F #join_trait_args) -> Self::Ret #method_where_clause;
                 }
+                #(#hook_traits)*
+                #continue_trait
                 #borrow_output_helpers
                 #flow_input_helpers
                 #(#induced_assoc_helpers)*
+                #join_fields
+        };
+        let blanket_where = quote! {
+            #blanket_assoc_where_preds
+            T: #blanket_inner_bound + ::inception::IsPrimitive<#property, Is = ::inception::False> #trait_supertrait_bounds,
+        };
+        let blanket_fn = quote! {
+            #asyncness fn #fn_ident #method_generic_params(#mutref #receiver #fn_args) -> #fn_ret_public #method_where_clause {
+                #blanket_dispatch_body
             }
-
-            #blanket_impl_head
-            where
-                #blanket_assoc_where_preds
-                T: #blanket_inner_bound + ::inception::IsPrimitive<#property, Is = ::inception::False> #trait_supertrait_bounds,
-            {
-                #(#assoc_impl_items)*
-                fn #fn_ident(#mutref #receiver #fn_args) -> #fn_ret_public {
-                    #blanket_dispatch_body
+        };
+        let join_impl = if gat_mode {
+            quote! {}
+        } else {
+            quote! {
+                impl<T, In, Out #flow_assoc_join_ret_generic #internal_trait_impl_generic_defs> #inner_trait<False, In, Out #internal_trait_generic_args> for T
+                where
+                    T: Inception<#property> + Meta,
+                    #join_group_bound
+                    #join_fields_inner_bound
+                    #join_named_output_eq_bound
+                    #(#join_where_preds,)*
+                {
+                    type Property = #property;
+                    type InTy = In;
+                    type OutTy = Out;
+                    type Ret = #join_ret_inductive;
+                    #asyncness fn #inner_fn #method_generic_params(#mutref #receiver #join_args) -> Self::Ret #method_where_clause {
+                        #fields_fn
+                        let f = #wrapper(#mutref fields);
+                        #join_comparator_body
+                        #path_push
+                        #enter_call
+                        let joined = <Self as #mod_ident :: Join #join_call_trait_args>::join #method_turbofish(f #join_arg_idents) #dot_await;
+                        #exit_call
+                        #path_pop
+                        joined
+                    }
                 }
             }
-
+        };
+        let impls = quote! {
             impl<T, In #internal_trait_impl_generic_defs> #mod_ident :: Nothing<In #internal_trait_generic_args> for T {
                 type InTy = In;
                 type OutTy = In;
                 type Ret = #nothing_ret;
-                fn nothing(#nothing_args) -> Self::Ret {
+                #asyncness fn nothing #method_generic_params(#nothing_args) -> Self::Ret #method_where_clause {
                     #nothing_body
                 }
             }
@@ -3848,11 +6356,13 @@ impl State {
                 F: Fields #phantom_bound,
                 L: Field #access_bound,
                 #merge_fields_ident: #merge_field_tail_bound,
+                #merge_line_up_bound
+                #merge_lined_up_bounds
             {
                 type InTy = In;
                 type OutTy = #merge_field_impl_out_ty;
                 type Ret = #merge_impl_ret_ty;
-                fn merge_field(#mutability #merge_head_arg: L, #mutability #merge_fields_arg: #merge_fields_ident #merge_args) -> Self::Ret {
+                #asyncness fn merge_field #method_generic_params(#mutability #merge_head_arg: L, #mutability #merge_fields_arg: #merge_fields_ident #merge_args) -> Self::Ret #method_where_clause {
                     #merge_impl_body
                 }
             }
@@ -3862,13 +6372,15 @@ impl State {
                 S: FieldsMeta + EnumMeta + VariantOffset<VAR_IDX>,
                 #merge_var_head_ident: #merge_variant_head_bound,
                 F: Fields #phantom_bound,
-                L: Field<Source = S> + VarField #try_access_bound,
+                L: Field<Source = S> + VarField #try_access_bound #merge_var_access_bound,
                 #merge_var_fields_ident: #merge_variant_tail_bound,
+                #merge_var_line_up_bound
+                #merge_var_lined_up_bounds
             {
                 type InTy = In;
                 type OutTy = #merge_variant_impl_out_ty;
                 type Ret = #merge_var_ret;
-                fn merge_variant_field(#mutability #merge_var_head_arg: L, #mutability #merge_var_fields_arg: #merge_var_fields_ident #merge_var_args) -> Self::Ret {
+                #asyncness fn merge_variant_field #method_generic_params(#mutability #merge_var_head_arg: L, #mutability #merge_var_fields_arg: #merge_var_fields_ident #merge_var_args) -> Self::Ret #method_where_clause {
                     #merge_var_body
                 }
             }
@@ -3880,11 +6392,14 @@ impl State {
                 type InTy = In;
                 type OutTy = #join_impl_out_ty;
                 type Ret = #join_impl_ret_ty;
-                fn join(#mutability #join_fields_arg: #join_fields_ident #join_trait_args) -> Self::Ret {
+                #asyncness fn join #method_generic_params(#mutability #join_fields_arg: #join_fields_ident #join_trait_args) -> Self::Ret #method_where_clause {
                     #join_impl_body
                 }
             }
 
+            #(#hook_impls)*
+            #continue_impl
+
             impl<T> Fields for #wrapper<#mutref T>
             where
                 T: Fields,
@@ -3906,14 +6421,17 @@ impl State {
 
             #split_impl
 
-            impl #empty_impl_generics #inner_trait #empty_impl_trait_args for #wrapper<#liferefelide List<()>> {
+            impl #empty_impl_generics #inner_trait #empty_impl_trait_args for #wrapper<#liferefelide List<()>>
+            where
+                #(#base_where_preds,)*
+            {
                 type Property = #property;
                 type InTy = <Self as #mod_ident :: Nothing<In #internal_trait_generic_args>>::InTy;
                 type OutTy = <Self as #mod_ident :: Nothing<In #internal_trait_generic_args>>::OutTy;
                 type Ret = #nothing_ret;
                 #[allow(unused)]
-                fn #inner_fn(#mutref #receiver #inner_fn_args) -> Self::Ret {
-                    <Self as #mod_ident :: Nothing<In #internal_trait_generic_args>>::nothing(#nothing_arg_idents)
+                #asyncness fn #inner_fn #method_generic_params(#mutref #receiver #inner_fn_args) -> Self::Ret #method_where_clause {
+                    <Self as #mod_ident :: Nothing<In #internal_trait_generic_args>>::nothing #method_turbofish(#nothing_arg_idents) #dot_await
                 }
             }
 
@@ -3926,21 +6444,25 @@ impl State {
                 #merge_split_bound
                 #merge_tail_bound
                 #merge_named_output_eq_bound
+                #merge_short_circuit_bound
+                #merge_fallible_bound
+                #merge_line_up_bound
+                #(#merge_where_preds,)*
             {
                 type Property = #property;
                 type InTy = In;
                 type OutTy = Out;
                 type Ret = #merge_ret_inductive;
-                fn #inner_fn(#mutref #receiver #inner_fn_args) -> Self::Ret {
+                #asyncness fn #inner_fn #method_generic_params(#mutref #receiver #inner_fn_args) -> Self::Ret #method_where_clause {
                     use #split_trait_ident;
                     let (#mutability l, #mutability r) = #dispatcher #split_fn_ident(#split_fn_receiver);
                     let #mutability r = #wrapper(r);
                     #merge_comparator_body
-                    <Self as #mod_ident :: MergeField #merge_call_trait_args>::merge_field(l, r #merge_arg_idents)
+                    #merge_call
                 }
             }
 
-            impl<#ret_lifetime_decl #merge_var_head_ident, S, const VAR_IDX: usize, const IDX: usize, F, In, Out #internal_trait_impl_generic_defs> #inner_trait<::inception::False, In, Out #internal_trait_generic_args>
+            impl<#ret_lifetime_decl #merge_var_head_ident, S, const VAR_IDX: usize, const IDX: usize, F, In, Out #merge_var_ret_generic #internal_trait_impl_generic_defs> #inner_trait<::inception::False, In, Out #internal_trait_generic_args>
                 for #merge_var_inductive_self_ty
             where
                 S: FieldsMeta + EnumMeta + VariantOffset<VAR_IDX>,
@@ -3949,39 +6471,43 @@ impl State {
                 <F as Fields>::Owned: Fields,
                 #merge_var_split_bound
                 #merge_var_tail_bound
+                #merge_var_short_circuit_bound
+                #merge_var_fallible_bound
+                #merge_var_line_up_bound
+                #(#merge_var_where_preds,)*
             {
                 type Property = #property;
                 type InTy = In;
                 type OutTy = Out;
                 type Ret = #merge_var_ret_inductive;
-                fn #inner_fn(#mutref #receiver #inner_fn_args) -> Self::Ret {
+                #asyncness fn #inner_fn #method_generic_params(#mutref #receiver #inner_fn_args) -> Self::Ret #method_where_clause {
                     use #split_trait_ident;
                     let (#mutability l, #mutability r) = #dispatcher #split_fn_ident(#split_fn_receiver);
                     let #mutability r = #wrapper(r);
                     #merge_var_comparator_body
-                    <Self as #mod_ident :: MergeVariantField #merge_variant_call_trait_args>::merge_variant_field(l, r #merge_var_arg_idents)
+                    #merge_var_call
                 }
             }
 
-            impl<T, In, Out #flow_assoc_join_ret_generic #internal_trait_impl_generic_defs> #inner_trait<False, In, Out #internal_trait_generic_args> for T
-            where
-                T: Inception<#property> + Meta,
-                #join_fields_inner_bound
-                #join_named_output_eq_bound
-            {
-                type Property = #property;
-                type InTy = In;
-                type OutTy = Out;
-                type Ret = #join_ret_inductive;
-                fn #inner_fn(#mutref #receiver #join_args) -> Self::Ret {
-                    #fields_fn
-                    let f = #wrapper(#mutref fields);
-                    #join_comparator_body
-                    <Self as #mod_ident :: Join #join_call_trait_args>::join(f #join_arg_idents)
-                }
-            }
+            #merge_variant_impls
+
+            #join_impl
         };
-        expanded.into()
+
+        Ok(Expansion {
+            property_ident,
+            trait_head,
+            assoc_trait_items: quote! { #(#assoc_trait_items)* },
+            trait_fn,
+            property_impls,
+            mod_ident,
+            module_body,
+            blanket_impl_head,
+            blanket_where,
+            assoc_impl_items: quote! { #(#assoc_impl_items)* },
+            blanket_fn,
+            impls,
+        })
     }
 
     fn finish_types_only(self, is_comparator: bool) -> TokenStream {
@@ -3994,6 +6520,7 @@ impl State {
             signature,
             vis,
             assoc_types,
+            assoc_consts,
             ..
         } = self;
 
@@ -4019,8 +6546,8 @@ impl State {
                 .into_compile_error()
                 .into();
         }
-        if assoc_types.is_empty() {
-            let msg = "`types` mode requires at least one associated type.";
+        if assoc_types.is_empty() && assoc_consts.iter().all(|c| c.induce.is_none()) {
+            let msg = "`types` mode requires at least one associated type or induced const.";
             return syn::Error::new_spanned(trait_ident.clone(), msg)
                 .into_compile_error()
                 .into();
@@ -4111,125 +6638,6 @@ impl State {
             }
         }
 
-        fn replace_type_ident(ty: &mut Type, target: &Ident, replacement: &Type) {
-            match ty {
-                Type::Path(TypePath { qself, path }) => {
-                    if qself.is_none() && path.is_ident(target) {
-                        *ty = replacement.clone();
-                        return;
-                    }
-                    if let Some(q) = qself {
-                        replace_type_ident(&mut q.ty, target, replacement);
-                    }
-                    for seg in path.segments.iter_mut() {
-                        match &mut seg.arguments {
-                            syn::PathArguments::AngleBracketed(args) => {
-                                for arg in args.args.iter_mut() {
-                                    match arg {
-                                        syn::GenericArgument::Type(inner) => {
-                                            replace_type_ident(inner, target, replacement)
-                                        }
-                                        syn::GenericArgument::AssocType(assoc) => {
-                                            replace_type_ident(&mut assoc.ty, target, replacement)
-                                        }
-                                        _ => {}
-                                    }
-                                }
-                            }
-                            syn::PathArguments::Parenthesized(args) => {
-                                for input in args.inputs.iter_mut() {
-                                    replace_type_ident(input, target, replacement);
-                                }
-                                if let ReturnType::Type(_, out) = &mut args.output {
-                                    replace_type_ident(out.as_mut(), target, replacement);
-                                }
-                            }
-                            syn::PathArguments::None => {}
-                        }
-                    }
-                }
-                Type::Reference(r) => replace_type_ident(r.elem.as_mut(), target, replacement),
-                Type::Ptr(p) => replace_type_ident(p.elem.as_mut(), target, replacement),
-                Type::Slice(s) => replace_type_ident(s.elem.as_mut(), target, replacement),
-                Type::Array(a) => replace_type_ident(a.elem.as_mut(), target, replacement),
-                Type::Tuple(t) => {
-                    for elem in t.elems.iter_mut() {
-                        replace_type_ident(elem, target, replacement);
-                    }
-                }
-                Type::Paren(p) => replace_type_ident(p.elem.as_mut(), target, replacement),
-                Type::Group(g) => replace_type_ident(g.elem.as_mut(), target, replacement),
-                _ => {}
-            }
-        }
-        fn replace_type_ident_in_path_args(
-            args: &mut syn::PathArguments,
-            target: &Ident,
-            replacement: &Type,
-        ) {
-            match args {
-                syn::PathArguments::AngleBracketed(ab) => {
-                    for arg in ab.args.iter_mut() {
-                        match arg {
-                            syn::GenericArgument::Type(inner) => {
-                                replace_type_ident(inner, target, replacement)
-                            }
-                            syn::GenericArgument::AssocType(assoc) => {
-                                replace_type_ident(&mut assoc.ty, target, replacement)
-                            }
-                            syn::GenericArgument::Constraint(constraint) => {
-                                for b in constraint.bounds.iter_mut() {
-                                    if let TypeParamBound::Trait(tb) = b {
-                                        for seg in tb.path.segments.iter_mut() {
-                                            replace_type_ident_in_path_args(
-                                                &mut seg.arguments,
-                                                target,
-                                                replacement,
-                                            );
-                                        }
-                                    }
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
-                }
-                syn::PathArguments::Parenthesized(pb) => {
-                    for input in pb.inputs.iter_mut() {
-                        replace_type_ident(input, target, replacement);
-                    }
-                    if let ReturnType::Type(_, out) = &mut pb.output {
-                        replace_type_ident(out.as_mut(), target, replacement);
-                    }
-                }
-                syn::PathArguments::None => {}
-            }
-        }
-        fn substitute_where_preds(
-            preds: &[WherePredicate],
-            replacements: &[(&Ident, &Type)],
-        ) -> Vec<WherePredicate> {
-            let mut out = preds.to_vec();
-            for pred in out.iter_mut() {
-                if let WherePredicate::Type(tp) = pred {
-                    for (target, replacement) in replacements.iter() {
-                        replace_type_ident(&mut tp.bounded_ty, target, replacement);
-                        for b in tp.bounds.iter_mut() {
-                            if let TypeParamBound::Trait(tb) = b {
-                                for seg in tb.path.segments.iter_mut() {
-                                    replace_type_ident_in_path_args(
-                                        &mut seg.arguments,
-                                        target,
-                                        replacement,
-                                    );
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-            out
-        }
         fn type_is_plain_ident(ty: &Type, ident: &Ident) -> bool {
             matches!(
                 ty,
@@ -4279,21 +6687,21 @@ impl State {
                             syn::PathArguments::AngleBracketed(args) => {
                                 for arg in args.args.iter() {
                                     match arg {
-                                        syn::GenericArgument::Type(inner) => {
+                                        syn::GenericArgument::Type(inner)
                                             if type_contains_helper_ret_projection(
                                                 inner,
                                                 helper_ident,
-                                            ) {
-                                                return true;
-                                            }
+                                            ) =>
+                                        {
+                                            return true;
                                         }
-                                        syn::GenericArgument::AssocType(assoc) => {
+                                        syn::GenericArgument::AssocType(assoc)
                                             if type_contains_helper_ret_projection(
                                                 &assoc.ty,
                                                 helper_ident,
-                                            ) {
-                                                return true;
-                                            }
+                                            ) =>
+                                        {
+                                            return true;
                                         }
                                         _ => {}
                                     }
@@ -5308,10 +7716,18 @@ impl State {
             })
             .collect::<Vec<_>>();
 
+        let assoc_const_items = assoc_consts.iter().map(|c| &c.item);
+        let InducedConsts {
+            helpers: const_helpers,
+            impl_items: const_impl_items,
+            blanket_where: const_blanket_where,
+        } = induced_consts(&assoc_consts, &trait_ident, &property_ident, &mod_ident);
+
         let expanded = quote! {
             pub struct #property_ident;
             #vis trait #trait_ident #trait_generic_params #trait_supertrait_clause #trait_where_clause {
                 #(#assoc_trait_items)*
+                #(#assoc_const_items)*
             }
 
             mod #mod_ident {
@@ -5335,14 +7751,17 @@ impl State {
 
                 #split_impl
                 #(#induced_assoc_helpers)*
+                #(#const_helpers)*
             }
 
             #blanket_impl_head
             where
                 #(#induced_blanket_where_preds)*
+                #(#const_blanket_where)*
                 T: ::inception::IsPrimitive<#property, Is = ::inception::False> #trait_supertrait_bounds,
             {
                 #(#assoc_impl_items)*
+                #(#const_impl_items)*
             }
         };
 
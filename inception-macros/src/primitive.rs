@@ -7,7 +7,10 @@ use syn::{parse_macro_input, GenericParam, ItemImpl, Path};
 #[derive(deluxe::ParseMetaItem, deluxe::ExtractAttributes)]
 #[deluxe(attributes(primitive))]
 struct Attributes {
-    property: Path,
+    #[deluxe(default)]
+    property: Option<Path>,
+    #[deluxe(default)]
+    properties: Vec<Path>,
 }
 
 pub struct State {}
@@ -47,10 +50,23 @@ impl State {
                     .to_compile_error()
                     .into();
                 };
-                let Ok(Attributes { property }) = deluxe::parse(attr) else {
-                    return syn::Error::new_spanned(x, "Expected \"property = ...\"")
+                let properties = match deluxe::parse(attr) {
+                    Ok(Attributes {
+                        property: Some(property),
+                        properties,
+                    }) if properties.is_empty() => vec![property],
+                    Ok(Attributes {
+                        property: None,
+                        properties,
+                    }) if !properties.is_empty() => properties,
+                    _ => {
+                        return syn::Error::new_spanned(
+                            x,
+                            "Expected either \"property = ...\" or \"properties = [...]\"",
+                        )
                         .into_compile_error()
                         .into();
+                    }
                 };
                 let self_ty_tokens = quote! { #self_ty }.to_string();
                 let retained_params = x
@@ -116,9 +132,11 @@ impl State {
                 quote! {
                     #input
                     const _: () = {
-                        impl #impl_generics ::inception::IsPrimitive<#property> for #self_ty #impl_where_clause {
-                            type Is = ::inception::True;
-                        }
+                        #(
+                            impl #impl_generics ::inception::IsPrimitive<#properties> for #self_ty #impl_where_clause {
+                                type Is = ::inception::True;
+                            }
+                        )*
                     };
                 }
                 .into()
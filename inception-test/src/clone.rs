@@ -13,6 +13,7 @@ pub trait Duplicate {
     ) -> <Self as Fields>::Owned {
         List((l.access().dupe().into(), r.dupe()))
     }
+    // Rebuilds headers and empty placeholders too, which the default `merge_variant_field` skips.
     fn merge_variant_field<H: Duplicate<Ret = H>, R: Duplicate<Ret = <F as Fields>::Owned>>(
         l: L,
         r: R,
@@ -57,7 +58,7 @@ impl Duplicate for String {
 #[primitive(property = DupeRef)]
 impl Duplicate for VariantHeader {
     fn dupe(&self) -> Self {
-        VariantHeader
+        *self
     }
 }
 
@@ -118,7 +119,7 @@ impl MutDupe for String {
 #[primitive(property = DupeMut)]
 impl MutDupe for VariantHeader {
     fn dupe_mut(&mut self) -> Self {
-        VariantHeader
+        *self
     }
 }
 
@@ -180,7 +181,7 @@ impl OwnDupe for String {
 #[primitive(property = DupeOwned)]
 impl OwnDupe for VariantHeader {
     fn dupe_owned(self) -> Self {
-        VariantHeader
+        self
     }
 }
 
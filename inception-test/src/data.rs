@@ -1,13 +1,15 @@
-use inception::Inception;
+use inception::{shape::Shape, Inception};
 
 use crate::clone::{DupeMut, DupeOwned, DupeRef};
-use crate::debug::{DebugRef, DebugTy};
+use crate::debug::{DebugOutline, DebugRef, DebugTy};
 use crate::default::Default;
-use crate::eq::SameSame;
+use crate::eq::{SameSame, ThreeWay};
 use crate::hash::Digestible;
+use crate::shape::{Matching, Positional};
+use crate::stats::Statistics;
 
 #[derive(Inception)]
-#[inception(properties = [Default, Digestible, SameSame, DebugTy, DebugRef, DupeRef, DupeMut, DupeOwned])]
+#[inception(properties = [Default, Digestible, SameSame, DebugTy, DebugRef, DebugOutline, DupeRef, DupeMut, DupeOwned, Statistics, ThreeWay, Matching, Shape])]
 pub struct Actor {
     pub name: String,
     pub kind: Kind,
@@ -15,14 +17,14 @@ pub struct Actor {
 }
 
 #[derive(Inception)]
-#[inception(properties = [Default, Digestible, SameSame, DebugTy, DebugRef, DupeRef, DupeMut, DupeOwned])]
+#[inception(properties = [Default, Digestible, SameSame, DebugTy, DebugRef, DebugOutline, DupeRef, DupeMut, DupeOwned, Statistics, ThreeWay, Matching, Shape])]
 pub enum Kind {
     BigName { salary: u64 },
     Aspiring { salary: u8 },
 }
 
 #[derive(Inception)]
-#[inception(properties = [Default, Digestible, SameSame, DebugTy, DebugRef, DupeRef, DupeMut, DupeOwned])]
+#[inception(properties = [Default, Digestible, SameSame, DebugTy, DebugRef, DebugOutline, DupeRef, DupeMut, DupeOwned, Statistics, ThreeWay])]
 pub struct Movie {
     pub title: String,
     pub year: u64,
@@ -32,7 +34,7 @@ pub struct Movie {
 }
 
 #[derive(Inception)]
-#[inception(properties = [Default, Digestible, SameSame, DebugTy, DebugRef, DupeRef, DupeMut, DupeOwned])]
+#[inception(properties = [Default, Digestible, SameSame, DebugTy, DebugRef, DebugOutline, DupeRef, DupeMut, DupeOwned, Statistics, ThreeWay, Matching, Positional, Shape])]
 pub struct Director {
     pub name: String,
     pub num_movies: u8,
@@ -40,7 +42,7 @@ pub struct Director {
 }
 
 #[derive(Inception)]
-#[inception(properties = [Default, Digestible, SameSame, DebugTy, DebugRef, DupeRef, DupeMut, DupeOwned])]
+#[inception(properties = [Default, Digestible, SameSame, DebugTy, DebugRef, DebugOutline, DupeRef, DupeMut, DupeOwned, Statistics, ThreeWay])]
 pub enum Version {
     One(Movie),
     Two(Movie),
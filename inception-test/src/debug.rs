@@ -77,7 +77,10 @@ pub trait DiagRef {
     }
 
     fn merge<H: DiagRef<Ret = String>, R: DiagRef<Ret = String>>(l: L, r: R) -> String {
-        let l = format!("{}: {}", <L as Field>::IDX, l.access().print());
+        let mut l = format!("{}: {}", <L as Field>::IDX, l.access().print());
+        if let Some(Annotation::Str(unit)) = <L as FieldAnnotations>::annotation("unit") {
+            l.push_str(unit);
+        }
         let r = r.print();
         format!("{l}\n{r}")
     }
@@ -91,11 +94,15 @@ pub trait DiagRef {
             <L as Field>::IDX,
             match l.try_access() {
                 Ok(f) => format!(
-                    "{}: {}",
+                    "{}: {}{}",
                     <L as VariantMeta>::VARIANT_FIELD_NAMES
                         .get(<L as Field>::IDX)
                         .unwrap_or(&"unnamed"),
-                    f.print()
+                    f.print(),
+                    match <L as FieldAnnotations>::annotation("unit") {
+                        Some(Annotation::Str(unit)) => unit,
+                        _ => "",
+                    }
                 ),
                 Err(RefEnumAccessError::Header(_)) =>
                     format!("[[{}]]", <L as VariantMeta>::VARIANT_NAME),
@@ -151,6 +158,201 @@ impl DiagRef for VariantHeader {
     }
 }
 
+#[inception(property = DebugOutline)]
+pub trait Outline {
+    fn outline(&self, depth: &mut usize) -> String;
+
+    fn nothing() -> String {
+        Default::default()
+    }
+
+    fn merge<H: Outline<Ret = String>, R: Outline<Ret = String>>(
+        l: L,
+        r: R,
+        depth: &mut usize,
+    ) -> String {
+        let indent = "   ".repeat(*depth);
+        let mut l = format!(
+            "{indent}{}: {}",
+            <L as Field>::IDX,
+            l.access().outline(depth)
+        );
+        if let Some(Annotation::Str(unit)) = <L as FieldAnnotations>::annotation("unit") {
+            l.push_str(unit);
+        }
+        format!("{l}\n{}", r.outline(depth))
+    }
+
+    fn merge_variant_field<H: Outline<Ret = String>, R: Outline<Ret = String>>(
+        l: L,
+        r: R,
+        depth: &mut usize,
+    ) -> String {
+        let indent = "   ".repeat(*depth);
+        let name = <L as VariantMeta>::VARIANT_FIELD_NAMES
+            .get(<L as Field>::IDX)
+            .unwrap_or(&"unnamed");
+        let l = match l.try_access() {
+            Ok(f) => format!("{name}: {}", f.outline(depth)),
+            Err(RefEnumAccessError::Header(_)) => {
+                format!("[[{}]]", <L as VariantMeta>::VARIANT_NAME)
+            }
+            Err(RefEnumAccessError::EmptyField(_)) => name.to_string(),
+        };
+        format!("{indent}{}: {l}\n{}", <L as Field>::IDX, r.outline(depth))
+    }
+
+    fn join<F: Outline<Ret = String>>(f: F, depth: &mut usize) -> String {
+        let content = f.outline(depth);
+        format!("{} {{\n{content}{}}}", Self::NAME, "   ".repeat(*depth - 1))
+    }
+
+    fn enter(_name: &'static str, depth: &mut usize) {
+        *depth += 1;
+    }
+
+    fn exit(_name: &'static str, depth: &mut usize) {
+        *depth -= 1;
+    }
+}
+
+#[primitive(property = DebugOutline)]
+impl Outline for u8 {
+    fn outline(&self, _depth: &mut usize) -> String {
+        self.to_string()
+    }
+}
+#[primitive(property = DebugOutline)]
+impl Outline for u64 {
+    fn outline(&self, _depth: &mut usize) -> String {
+        self.to_string()
+    }
+}
+#[primitive(property = DebugOutline)]
+impl Outline for u128 {
+    fn outline(&self, _depth: &mut usize) -> String {
+        self.to_string()
+    }
+}
+#[primitive(property = DebugOutline)]
+impl Outline for String {
+    fn outline(&self, _depth: &mut usize) -> String {
+        self.clone()
+    }
+}
+#[primitive(property = DebugOutline)]
+impl Outline for VariantHeader {
+    fn outline(&self, _depth: &mut usize) -> String {
+        "__variant__".to_string()
+    }
+}
+
+#[inception(property = DebugSummary)]
+pub trait Summary {
+    fn summary(&self) -> String;
+
+    fn nothing() -> String {
+        Default::default()
+    }
+
+    fn merge<H: Summary<Ret = String>, R: Summary<Ret = String>>(_l: L, _r: R) -> String {
+        " { .. }".to_string()
+    }
+
+    fn merge_variant_field<H: Summary<Ret = String>, R: Summary<Ret = String>>(
+        _l: L,
+        r: R,
+    ) -> String {
+        let l = match <L as VariantMeta>::VARIANT_FIELD_NAMES.get(<L as Field>::IDX) {
+            Some(name) => format!("{name}: .."),
+            None => "..".to_string(),
+        };
+        match r.summary() {
+            r if r.is_empty() => l,
+            r => format!("{l}, {r}"),
+        }
+    }
+
+    fn merge_variant<V: Summary<Ret = String>, R: Summary<Ret = String>>(
+        l: L,
+        v: V,
+        r: R,
+    ) -> String {
+        if !matches!(l.try_access(), Err(RefEnumAccessError::Header(_))) {
+            return r.summary();
+        }
+        let name = <L as VariantMeta>::VARIANT_NAME;
+        match v.summary() {
+            fields if fields.is_empty() => format!("::{name}"),
+            fields if <L as VariantMeta>::VARIANT_FIELD_NAMES.is_empty() => {
+                format!("::{name}({fields})")
+            }
+            fields => format!("::{name} {{ {fields} }}"),
+        }
+    }
+
+    fn join<F: Summary<Ret = String>>(f: F) -> String {
+        format!("{}{}", Self::NAME, f.summary())
+    }
+}
+
+primitives!(u8 => [DebugSummary, VariantCount]);
+
+impl Summary for u8 {
+    fn summary(&self) -> String {
+        self.to_string()
+    }
+}
+#[primitive(properties = [DebugSummary, VariantCount])]
+impl Summary for u64 {
+    fn summary(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[inception(property = VariantCount)]
+pub trait CountVariants {
+    fn count_variants() -> usize;
+
+    fn nothing() -> usize {
+        0
+    }
+
+    fn merge<H: CountVariants<Ret = usize>, R: CountVariants<Ret = usize>>(_l: L, _r: R) -> usize {
+        0
+    }
+
+    fn merge_variant_field<H: CountVariants<Ret = usize>, R: CountVariants<Ret = usize>>(
+        _l: L,
+        _r: R,
+    ) -> usize {
+        0
+    }
+
+    fn merge_variant<V: CountVariants<Ret = usize>, R: CountVariants<Ret = usize>>(
+        _l: L,
+        _v: V,
+        _r: R,
+    ) -> usize {
+        1 + R::count_variants()
+    }
+
+    fn join<F: CountVariants<Ret = usize>>(_f: F) -> usize {
+        F::count_variants()
+    }
+}
+
+impl CountVariants for u8 {
+    fn count_variants() -> usize {
+        0
+    }
+}
+impl CountVariants for u64 {
+    fn count_variants() -> usize {
+        0
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{DiagTy, *};
@@ -170,4 +372,286 @@ mod test {
         let s = data.print();
         println!("{s}");
     }
+
+    #[test]
+    fn outline() {
+        use crate::default::Standard;
+
+        let data = Movie::standard();
+        let mut depth = 0;
+        assert_eq!(data.outline(&mut depth), data.print());
+        assert_eq!(depth, 0);
+
+        let mut depth = 0;
+        let status = Status::StillActive { last_seen: 3 };
+        assert_eq!(status.outline(&mut depth), status.print());
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [DebugRef], rename_all = "camelCase")]
+    struct Renamed {
+        net_worth: u64,
+        #[inception(rename = "years")]
+        age: u8,
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [DebugRef, DebugOutline], rename_all = "kebab-case")]
+    enum Status {
+        #[inception(rename_all = "SCREAMING_SNAKE_CASE")]
+        StillActive { last_seen: u64 },
+        #[inception(rename = "gone")]
+        Retired(u8),
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [DebugRef], rename_all = "snake_case")]
+    #[allow(dead_code)]
+    enum Endpoint {
+        HTTPServer,
+        IOError(u8),
+        Utf8Path,
+    }
+
+    #[test]
+    fn renamed_metadata() {
+        use inception::{EnumMeta, NamedFieldsMeta};
+
+        assert_eq!(Renamed::FIELD_NAMES, &["netWorth", "years"]);
+        assert_eq!(Status::VARIANT_NAMES, &["still-active", "gone"]);
+        assert_eq!(
+            Endpoint::VARIANT_NAMES,
+            &["http_server", "io_error", "utf8_path"]
+        );
+        assert_eq!(
+            <Status as EnumMeta>::FIELD_NAMES,
+            &[&["LAST_SEEN"][..], &[][..]]
+        );
+
+        let s = Status::StillActive { last_seen: 3 }.print();
+        assert!(s.contains("LAST_SEEN: 3"));
+        let _ = Renamed {
+            net_worth: 0,
+            age: 0,
+        }
+        .print();
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [DebugRef])]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Opcode {
+        Nop,
+        Load(u8) = 4,
+        Store(u8),
+        Halt = 0xff,
+    }
+
+    #[test]
+    fn discriminants() {
+        use inception::EnumMeta;
+
+        assert_eq!(Opcode::DISCRIMINANTS, &[0, 4, 5, 255]);
+        assert_eq!(Opcode::REPR, Some("u8"));
+        assert_eq!(Status::DISCRIMINANTS, &[0, 1]);
+        assert_eq!(Status::REPR, None);
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [DebugRef])]
+    struct Typed<'a> {
+        bytes: Vec<u8>,
+        label: Option<&'a str>,
+        #[inception(skip)]
+        _cache: u64,
+    }
+
+    #[test]
+    fn field_type_names() {
+        use inception::{EnumMeta, StructMeta};
+
+        assert_eq!(Typed::FIELD_TYPE_NAMES, &["Vec<u8>", "Option<&'a str>"]);
+        assert_eq!(
+            <Movie as StructMeta>::FIELD_TYPE_NAMES,
+            &["String", "u64", "Actor", "Actor", "Director"]
+        );
+        assert_eq!(
+            <Opcode as EnumMeta>::FIELD_TYPE_NAMES,
+            &[&[][..], &["u8"][..], &["u8"][..], &[][..]]
+        );
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [DebugRef])]
+    struct Wrapper<T, const N: usize> {
+        inner: T,
+    }
+
+    #[test]
+    fn generic_type_name() {
+        use inception::DataType;
+
+        assert_eq!(Movie::type_name().to_string(), "Movie");
+        assert_eq!(Wrapper::<u8, 2>::type_name().to_string(), "Wrapper<u8, 2>");
+        assert_eq!(
+            Wrapper::<Wrapper<Vec<String>, 0>, 1>::type_name().to_string(),
+            "Wrapper<Wrapper<Vec<String>, 0>, 1>"
+        );
+        assert_eq!(
+            Wrapper::<Option<&str>, 3>::type_name().to_string(),
+            "Wrapper<Option<&str>, 3>"
+        );
+        assert_eq!(
+            Wrapper::<(u8, String), 4>::type_name().to_string(),
+            "Wrapper<(u8, String), 4>"
+        );
+        assert_eq!(
+            Wrapper::<Option<(u8,)>, 5>::type_name().to_string(),
+            "Wrapper<Option<(u8,)>, 5>"
+        );
+        assert_eq!(Wrapper::<u8, 2>::NAME, "Wrapper");
+    }
+
+    /// A reading from a sensor.
+    ///
+    /// Values are raw.
+    #[derive(Inception)]
+    #[inception(properties = [DebugRef])]
+    struct Sample {
+        /// Milliseconds since boot.
+        at: u64,
+        value: u8,
+    }
+
+    /// Power state.
+    #[derive(Inception)]
+    #[inception(properties = [DebugRef])]
+    enum Power {
+        /// Running on battery.
+        Battery {
+            /// Percent remaining.
+            level: u8,
+        },
+        Mains(
+            /// Volts.
+            u8,
+        ),
+    }
+
+    /// A reading without field names.
+    #[derive(Inception)]
+    #[inception(properties = [DebugRef])]
+    struct Raw(
+        /// Channel.
+        u8,
+        u64,
+    );
+
+    #[test]
+    fn docs() {
+        use inception::{DataType, EnumMeta, NamedFieldsMeta, UnnamedFieldsMeta};
+
+        assert_eq!(Sample::DOCS, "A reading from a sensor.\n\nValues are raw.");
+        assert_eq!(Sample::FIELD_DOCS, &["Milliseconds since boot.", ""]);
+        assert_eq!(Power::DOCS, "Power state.");
+        assert_eq!(Power::VARIANT_DOCS, &["Running on battery.", ""]);
+        assert_eq!(
+            <Power as EnumMeta>::FIELD_DOCS,
+            &[&["Percent remaining."][..], &["Volts."][..]]
+        );
+        assert_eq!(Raw::DOCS, "A reading without field names.");
+        assert_eq!(<Raw as UnnamedFieldsMeta>::FIELD_DOCS, &["Channel.", ""]);
+        assert_eq!(Movie::DOCS, "");
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [DebugRef])]
+    struct Gauge {
+        #[inception(meta(unit = "ms", min = 0, max = 100))]
+        latency: u64,
+        #[inception(meta(scale = -1.5, required))]
+        level: u8,
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [DebugRef])]
+    enum Reading {
+        Idle,
+        Busy(#[inception(meta(unit = "%"))] u8),
+    }
+
+    #[test]
+    fn annotations() {
+        use inception::{Annotation, StructMeta};
+
+        assert_eq!(
+            Gauge::FIELD_ANNOTATIONS,
+            &[
+                &[
+                    ("unit", Annotation::Str("ms")),
+                    ("min", Annotation::Int(0)),
+                    ("max", Annotation::Int(100))
+                ][..],
+                &[
+                    ("scale", Annotation::Float(-1.5)),
+                    ("required", Annotation::Bool(true))
+                ][..],
+            ]
+        );
+
+        let s = Gauge {
+            latency: 12,
+            level: 3,
+        }
+        .print();
+        assert!(s.contains("0: 12ms"));
+        assert!(s.contains("1: 3\n"));
+
+        type Header = VarTyField<VariantHeader, Reading, 1, { field::HEADER_IDX }>;
+        assert_eq!(<Header as FieldAnnotations>::ANNOTATIONS, &[]);
+        assert_eq!(<Header as Field>::IDX, 0);
+        const { assert!(<Header as Field>::IS_HEADER) };
+        type Busy = VarTyField<u8, Reading, 1, 0>;
+        assert_eq!(
+            <Busy as FieldAnnotations>::ANNOTATIONS,
+            &[("unit", Annotation::Str("%"))]
+        );
+        const { assert!(!<Busy as Field>::IS_HEADER) };
+
+        let s = Reading::Busy(40).print();
+        assert!(s.contains("[[Busy]]"));
+        assert!(s.contains("unnamed: 40%"));
+        let _ = Reading::Idle.print();
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [DebugSummary, VariantCount])]
+    enum Kind {
+        BigName { salary: u64, age: u8 },
+        Pair(u8, u8),
+        Nobody,
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [DebugSummary, VariantCount])]
+    struct Payroll {
+        total: u64,
+    }
+
+    #[test]
+    fn merge_variant() {
+        let s = Kind::BigName {
+            salary: 100,
+            age: 30,
+        }
+        .summary();
+        assert_eq!(s, "Kind::BigName { salary: .., age: .. }");
+        assert_eq!(Kind::Pair(1, 2).summary(), "Kind::Pair(.., ..)");
+        assert_eq!(Kind::Nobody.summary(), "Kind::Nobody");
+        assert_eq!(Payroll { total: 3 }.summary(), "Payroll { .. }");
+
+        assert_eq!(Kind::count_variants(), 3);
+        assert_eq!(Payroll::count_variants(), 0);
+    }
 }
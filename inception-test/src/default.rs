@@ -51,12 +51,14 @@ impl Standard for String {
 #[primitive(property = Default)]
 impl Standard for VariantHeader {
     fn standard() -> Self {
-        VariantHeader
+        VariantHeader::default()
     }
 }
 
 #[cfg(test)]
 mod test {
+    use core::marker::PhantomData;
+
     use super::*;
     use crate::data::Movie;
 
@@ -64,4 +66,77 @@ mod test {
     fn default() {
         let _s = Movie::standard();
     }
+
+    #[derive(Inception)]
+    #[inception(properties = [Default])]
+    struct Cached<T> {
+        value: u64,
+        #[inception(skip)]
+        cache: Option<u64>,
+        #[inception(skip = PhantomData)]
+        _marker: PhantomData<T>,
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [Default])]
+    enum Reading {
+        Raw(#[inception(skip = 7)] u8, u64),
+        Calibrated {
+            value: u64,
+            #[inception(skip)]
+            offset: u8,
+        },
+    }
+
+    #[test]
+    fn skipped_fields() {
+        let c = Cached::<String>::standard();
+        assert_eq!(c.value, 0);
+        assert_eq!(c.cache, None);
+
+        let Reading::Raw(flag, value) = Reading::standard() else {
+            panic!("expected the first variant");
+        };
+        assert_eq!((flag, value), (7, 0));
+
+        let r = Reading::Calibrated {
+            value: 3,
+            offset: 2,
+        };
+        let r = <Reading as Inception<Default>>::from_fields(Inception::<Default>::into_fields(r));
+        let Reading::Calibrated { value, offset } = r else {
+            panic!("expected the second variant");
+        };
+        assert_eq!((value, offset), (3, 0));
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Port(u16);
+
+    #[derive(Inception)]
+    #[inception(properties = [Default])]
+    struct Server {
+        workers: u8,
+        #[inception(skip(Default, default = Port(8080)))]
+        port: Port,
+    }
+
+    #[test]
+    fn per_property_skip_with_default() {
+        let s = Server::standard();
+        assert_eq!(s.workers, 0);
+        assert_eq!(s.port, Port(8080));
+    }
+
+    #[test]
+    fn no_active_variant() {
+        use crate::data::Kind;
+
+        let fields = <<Kind as Inception<Default>>::OwnedFields as Phantom>::phantom();
+        let Err(e) = <Kind as Inception<Default>>::try_from_fields(fields) else {
+            panic!("expected no variant to be active");
+        };
+        assert_eq!(e, FromFieldsError::NoVariant { name: "Kind" });
+        assert_eq!(e.to_string(), "Failed to determine enum variant of `Kind`.");
+    }
 }
@@ -1,60 +1,152 @@
+use std::ops::ControlFlow;
+
 use inception::*;
 
-#[inception(property = SameSame, comparator)]
+#[inception(property = SameSame, comparator, short_circuit)]
 pub trait Same {
-    fn same(&self, _other: &Self) -> bool;
+    fn same(&self, _other: &Self) -> ControlFlow<()>;
 
-    fn nothing() -> bool {
-        true
+    fn nothing() -> ControlFlow<()> {
+        ControlFlow::Continue(())
     }
-    fn merge<H: Same<Ret = bool>, R: Same<Ret = bool>>(l: L, r: R, l2: L, r2: R) -> bool {
-        l.access().same(l2.access()) && r.same(&r2)
+    fn merge<H: Same<Ret = ControlFlow<()>>, R: Same<Ret = ControlFlow<()>>>(
+        l: L,
+        _r: R,
+        l2: L,
+        _r2: R,
+    ) -> ControlFlow<()> {
+        l.access().same(l2.access())
     }
-    fn merge_variant_field<H: Same<Ret = bool>, R: Same<Ret = bool>>(
+    // Fields of differing variants are unequal, so comparators can't use the default.
+    fn merge_variant_field<H: Same<Ret = ControlFlow<()>>, R: Same<Ret = ControlFlow<()>>>(
         l: L,
-        r: R,
+        _r: R,
         l2: L,
-        r2: R,
-    ) -> bool {
+        _r2: R,
+    ) -> ControlFlow<()> {
         match (l.try_access(), l2.try_access()) {
-            (Ok(l), Ok(l2)) => l.same(l2) && r.same(&r2),
-            (Err(_), Err(_)) => true,
-            _ => false,
+            (Ok(l), Ok(l2)) => l.same(l2),
+            (Err(_), Err(_)) => ControlFlow::Continue(()),
+            _ => ControlFlow::Break(()),
         }
     }
-    fn join<F: Same<Ret = bool>>(fields: F, fields2: F) -> bool {
+    fn join<F: Same<Ret = ControlFlow<()>>>(fields: F, fields2: F) -> ControlFlow<()> {
         fields.same(&fields2)
     }
 }
 
+fn same<T: PartialEq>(a: &T, b: &T) -> ControlFlow<()> {
+    if a == b {
+        ControlFlow::Continue(())
+    } else {
+        ControlFlow::Break(())
+    }
+}
+
 #[primitive(property = SameSame)]
 impl Same for u8 {
-    fn same(&self, other: &Self) -> bool {
-        self == other
+    fn same(&self, other: &Self) -> ControlFlow<()> {
+        same(self, other)
     }
 }
 #[primitive(property = SameSame)]
 impl Same for u64 {
-    fn same(&self, other: &Self) -> bool {
-        self == other
+    fn same(&self, other: &Self) -> ControlFlow<()> {
+        same(self, other)
     }
 }
 #[primitive(property = SameSame)]
 impl Same for u128 {
-    fn same(&self, other: &Self) -> bool {
-        self == other
+    fn same(&self, other: &Self) -> ControlFlow<()> {
+        same(self, other)
     }
 }
 #[primitive(property = SameSame)]
 impl Same for String {
-    fn same(&self, other: &Self) -> bool {
-        self == other
+    fn same(&self, other: &Self) -> ControlFlow<()> {
+        same(self, other)
     }
 }
 #[primitive(property = SameSame)]
 impl Same for VariantHeader {
-    fn same(&self, _other: &Self) -> bool {
-        true
+    fn same(&self, other: &Self) -> ControlFlow<()> {
+        same(self, other)
+    }
+}
+
+#[inception(property = ThreeWay, comparator(arity = 3))]
+pub trait Conflicts {
+    fn conflicts(&self, _ours: &Self, _theirs: &Self) -> usize;
+
+    fn nothing() -> usize {
+        0
+    }
+    fn merge<H: Conflicts<Ret = usize>, R: Conflicts<Ret = usize>>(
+        l: L,
+        r: R,
+        l2: L,
+        r2: R,
+        l3: L,
+        r3: R,
+    ) -> usize {
+        l.access().conflicts(l2.access(), l3.access()) + r.conflicts(&r2, &r3)
+    }
+    fn merge_variant_field<H: Conflicts<Ret = usize>, R: Conflicts<Ret = usize>>(
+        l: L,
+        r: R,
+        l2: L,
+        r2: R,
+        l3: L,
+        r3: R,
+    ) -> usize {
+        let head = match (l.try_access(), l2.try_access(), l3.try_access()) {
+            (Ok(l), Ok(l2), Ok(l3)) => l.conflicts(l2, l3),
+            (
+                Err(RefEnumAccessError::Header(l)),
+                Err(RefEnumAccessError::Header(l2)),
+                Err(RefEnumAccessError::Header(l3)),
+            ) => l.access().conflicts(l2.access(), l3.access()),
+            _ => 0,
+        };
+        head + r.conflicts(&r2, &r3)
+    }
+    fn join<F: Conflicts<Ret = usize>>(fields: F, fields2: F, fields3: F) -> usize {
+        fields.conflicts(&fields2, &fields3)
+    }
+}
+
+fn conflict<T: PartialEq>(base: &T, ours: &T, theirs: &T) -> usize {
+    usize::from(ours != base && theirs != base && ours != theirs)
+}
+
+#[primitive(property = ThreeWay)]
+impl Conflicts for u8 {
+    fn conflicts(&self, ours: &Self, theirs: &Self) -> usize {
+        conflict(self, ours, theirs)
+    }
+}
+#[primitive(property = ThreeWay)]
+impl Conflicts for u64 {
+    fn conflicts(&self, ours: &Self, theirs: &Self) -> usize {
+        conflict(self, ours, theirs)
+    }
+}
+#[primitive(property = ThreeWay)]
+impl Conflicts for u128 {
+    fn conflicts(&self, ours: &Self, theirs: &Self) -> usize {
+        conflict(self, ours, theirs)
+    }
+}
+#[primitive(property = ThreeWay)]
+impl Conflicts for String {
+    fn conflicts(&self, ours: &Self, theirs: &Self) -> usize {
+        conflict(self, ours, theirs)
+    }
+}
+#[primitive(property = ThreeWay)]
+impl Conflicts for VariantHeader {
+    fn conflicts(&self, ours: &Self, theirs: &Self) -> usize {
+        conflict(self, ours, theirs)
     }
 }
 
@@ -66,21 +158,25 @@ where
     T: Same,
 {
     fn different(&self, other: &Self) -> bool {
-        !self.same(other)
+        self.same(other).is_break()
     }
 }
 
 #[cfg(test)]
 mod test {
     use crate::data::Movie;
+    use crate::debug::DebugRef;
     use crate::default::Standard;
+    use crate::hash::Digestible;
+
+    use std::cell::Cell;
 
     use super::*;
 
     #[test]
     fn sameness() {
         let m = Movie::standard();
-        assert!(m.same(&m));
+        assert!(m.same(&m).is_continue());
     }
 
     #[test]
@@ -88,4 +184,116 @@ mod test {
         let m = Movie::standard();
         assert!(!m.different(&m));
     }
+
+    thread_local! {
+        static COMPARED: Cell<usize> = const { Cell::new(0) };
+    }
+    fn compared() -> usize {
+        COMPARED.with(|c| c.replace(0))
+    }
+
+    struct Probe(u8);
+    #[primitive(property = SameSame)]
+    impl Same for Probe {
+        fn same(&self, other: &Self) -> ControlFlow<()> {
+            COMPARED.with(|c| c.set(c.get() + 1));
+            same(&self.0, &other.0)
+        }
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [SameSame])]
+    struct Probes {
+        first: Probe,
+        second: Probe,
+        third: Probe,
+    }
+
+    #[test]
+    fn first_mismatch() {
+        let probes = |first, second, third| Probes {
+            first: Probe(first),
+            second: Probe(second),
+            third: Probe(third),
+        };
+        compared();
+        assert!(probes(1, 2, 3).same(&probes(1, 2, 3)).is_continue());
+        assert_eq!(compared(), 3);
+        assert!(probes(1, 2, 3).same(&probes(0, 2, 3)).is_break());
+        assert_eq!(compared(), 1);
+        assert!(probes(1, 2, 3).same(&probes(1, 2, 0)).is_break());
+        assert_eq!(compared(), 3);
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [SameSame, Digestible, DebugRef])]
+    struct Annotated {
+        value: u64,
+        #[inception(skip(SameSame, Digestible))]
+        note: String,
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [SameSame, DebugRef])]
+    enum Entry {
+        Plain(u64),
+        Noted {
+            value: u64,
+            #[inception(skip(crate::eq::SameSame))]
+            note: String,
+        },
+    }
+
+    #[test]
+    fn per_property_skip() {
+        use crate::debug::DiagRef;
+        use crate::hash::Digest;
+        use std::hash::{DefaultHasher, Hasher};
+
+        let a = Annotated {
+            value: 1,
+            note: "first".to_string(),
+        };
+        let b = Annotated {
+            value: 1,
+            note: "second".to_string(),
+        };
+        assert!(a.same(&b).is_continue());
+
+        let digest = |x: &Annotated| {
+            let mut h = DefaultHasher::new();
+            x.digest(&mut h);
+            h.finish()
+        };
+        assert_eq!(digest(&a), digest(&b));
+
+        assert!(a.print().contains("first"));
+        assert!(b.print().contains("second"));
+
+        let noted = |note: &str| Entry::Noted {
+            value: 2,
+            note: note.to_string(),
+        };
+        assert!(noted("first").same(&noted("second")).is_continue());
+        assert!(Entry::Plain(2).same(&Entry::Plain(2)).is_continue());
+        assert!(noted("first").print().contains("first"));
+    }
+
+    #[test]
+    fn three_way() {
+        let base = Movie::standard();
+
+        let mut ours = Movie::standard();
+        ours.title = "Interstellar".to_string();
+        let mut theirs = Movie::standard();
+        theirs.director.age += 1;
+        assert_eq!(base.conflicts(&ours, &theirs), 0);
+
+        ours.year = 2014;
+        theirs.year = 2015;
+        ours.lead.net_worth = 1;
+        theirs.lead.net_worth = 2;
+        assert_eq!(base.conflicts(&ours, &theirs), 2);
+        assert_eq!(base.conflicts(&ours, &ours), 0);
+    }
 }
@@ -1,27 +1,32 @@
+use std::hash::Hasher;
+
 use inception::*;
 
 #[inception(property = Digestible)]
 pub trait Digest {
-    fn digest(&self, state: &mut std::hash::DefaultHasher) -> ();
+    fn digest<W: Hasher>(&self, state: &mut W) -> ();
 
     fn nothing() -> bool {
         true
     }
-    fn merge<H: Digest, R: Digest>(l: L, r: R, state: &mut std::hash::DefaultHasher) -> () {
+    fn merge<H: Digest, R: Digest>(l: L, r: R, state: &mut W) -> () {
         l.access().digest(state);
         r.digest(state);
     }
-    fn merge_variant_field<H: Digest, R: Digest>(
-        l: L,
-        r: R,
-        state: &mut std::hash::DefaultHasher,
-    ) -> () {
-        if let Ok(value) = l.try_access() {
-            value.digest(state);
+    // Hashes the variant header as well, which the default `merge_variant_field` skips.
+    fn merge_variant_field<H: Digest, R: Digest>(l: L, r: R, state: &mut W) -> () {
+        match l.try_access() {
+            Ok(value) => {
+                value.digest(state);
+            }
+            Err(RefEnumAccessError::Header(header)) => {
+                header.access().digest(state);
+            }
+            Err(RefEnumAccessError::EmptyField(_)) => {}
         }
         r.digest(state);
     }
-    fn join<F: Digest>(fields: F, state: &mut std::hash::DefaultHasher) -> () {
+    fn join<F: Digest>(fields: F, state: &mut W) -> () {
         fields.digest(state);
     }
 }
@@ -29,40 +34,40 @@ pub trait Digest {
 use std::hash::Hash;
 #[primitive(property = Digestible)]
 impl Digest for u8 {
-    fn digest(&self, state: &mut std::hash::DefaultHasher) {
+    fn digest<W: Hasher>(&self, state: &mut W) {
         use std::hash::Hash;
         self.hash(state);
     }
 }
 #[primitive(property = Digestible)]
 impl Digest for u64 {
-    fn digest(&self, state: &mut std::hash::DefaultHasher) {
+    fn digest<W: Hasher>(&self, state: &mut W) {
         use std::hash::Hash;
         self.hash(state);
     }
 }
 #[primitive(property = Digestible)]
 impl Digest for u128 {
-    fn digest(&self, state: &mut std::hash::DefaultHasher) {
+    fn digest<W: Hasher>(&self, state: &mut W) {
         use std::hash::Hash;
         self.hash(state);
     }
 }
 #[primitive(property = Digestible)]
 impl Digest for String {
-    fn digest(&self, state: &mut std::hash::DefaultHasher) {
+    fn digest<W: Hasher>(&self, state: &mut W) {
         self.hash(state);
     }
 }
 #[primitive(property = Digestible)]
 impl Digest for VariantHeader {
-    fn digest(&self, _state: &mut std::hash::DefaultHasher) {}
+    fn digest<W: Hasher>(&self, state: &mut W) {
+        self.index().hash(state);
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use std::hash::Hasher;
-
     use crate::data::{Actor, Movie};
     use crate::default::Standard;
 
@@ -83,4 +88,54 @@ mod test {
 
         assert_ne!(a, b);
     }
+
+    #[test]
+    fn variant_discriminant() {
+        use crate::data::Version;
+        use std::hash::DefaultHasher;
+
+        let one = Version::One(Movie::standard());
+        let two = Version::Two(Movie::standard());
+        assert_eq!(Inception::<Digestible>::variant_index(&one), 0);
+        assert_eq!(Inception::<Digestible>::variant_index(&two), 1);
+        assert_eq!(Inception::<Digestible>::variant_name(&two), "Two");
+        let movie = Movie::standard();
+        assert_eq!(Inception::<Digestible>::variant_index(&movie), 0);
+        assert_eq!(Inception::<Digestible>::variant_name(&movie), "Movie");
+
+        let mut h = DefaultHasher::new();
+        one.digest(&mut h);
+        let a = h.finish();
+
+        let mut h = DefaultHasher::new();
+        two.digest(&mut h);
+        let b = h.finish();
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn generic_hasher() {
+        struct Fnv(u64);
+        impl Hasher for Fnv {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+            fn write(&mut self, bytes: &[u8]) {
+                for b in bytes {
+                    self.0 = (self.0 ^ *b as u64).wrapping_mul(0x100000001b3);
+                }
+            }
+        }
+
+        let mut a = Fnv(0xcbf29ce484222325);
+        Movie::standard().digest(&mut a);
+        let mut b = Fnv(0xcbf29ce484222325);
+        Movie::standard().digest(&mut b);
+        let mut c = Fnv(0xcbf29ce484222325);
+        Actor::standard().digest(&mut c);
+
+        assert_eq!(a.finish(), b.finish());
+        assert_ne!(a.finish(), c.finish());
+    }
 }
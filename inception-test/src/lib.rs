@@ -8,4 +8,9 @@ pub mod eq;
 pub mod hash;
 pub mod lifetime;
 pub mod perform;
+pub mod persist;
+pub mod search;
+pub mod shape;
+pub mod stats;
 pub mod types;
+pub mod validate;
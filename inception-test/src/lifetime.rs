@@ -30,6 +30,7 @@ trait LifetimeCountTy {
 }
 
 #[primitive(property = LifetimeCountProperty)]
+#[allow(clippy::needless_lifetimes)]
 impl<'a> LifetimeCountTy for &'a u8 {
     fn count() -> usize {
         1
@@ -37,6 +38,7 @@ impl<'a> LifetimeCountTy for &'a u8 {
 }
 
 #[primitive(property = LifetimeCountProperty)]
+#[allow(clippy::needless_lifetimes)]
 impl<'a> LifetimeCountTy for &'a str {
     fn count() -> usize {
         1
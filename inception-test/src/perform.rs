@@ -81,6 +81,18 @@ pub struct NestedPipeline {
 #[inception(property = PerformRef, signature(input = Input, output = Output))]
 pub trait RefPerformer<Input> {
     type Output;
+    #[induce(
+        base = List<()>,
+        merge = List<(
+            <Head as RefPerformer<In>>::Stages<'a>,
+            <Tail as RefPerformer<<Head as RefPerformer<In>>::Output>>::Stages<'a>,
+        )>,
+        merge_variant = <Tail as RefPerformer<In>>::Stages<'a>,
+        join = <Fields as RefPerformer<In>>::Stages<'a>
+    )]
+    type Stages<'a>
+    where
+        Self: 'a;
 
     fn perform_ref(&self, input: Input) -> Self::Output;
 
@@ -114,6 +126,7 @@ pub struct RefLen;
 #[primitive(property = PerformRef)]
 impl RefPerformer<u32> for RefToText {
     type Output = String;
+    type Stages<'a> = &'a Self;
 
     fn perform_ref(&self, input: u32) -> Self::Output {
         format!("{input}")
@@ -123,6 +136,7 @@ impl RefPerformer<u32> for RefToText {
 #[primitive(property = PerformRef)]
 impl RefPerformer<String> for RefLen {
     type Output = usize;
+    type Stages<'a> = &'a Self;
 
     fn perform_ref(&self, input: String) -> Self::Output {
         input.len()
@@ -279,6 +293,8 @@ mod test {
         let out = p.perform_ref(42_u32);
         let _: usize = out;
         assert_eq!(out, 2);
+        let _: <RefPipeline as RefPerformer<u32>>::Stages<'_> =
+            List((&p._a, List((&p._b, List(())))));
     }
 
     #[test]
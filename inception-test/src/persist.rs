@@ -0,0 +1,169 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use inception::*;
+
+/// Destination which accepts one byte per poll, yielding in between.
+#[derive(Default)]
+pub struct Journal {
+    pub bytes: Vec<u8>,
+    pub capacity: usize,
+    pending: bool,
+}
+impl Journal {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ..Default::default()
+        }
+    }
+
+    pub fn write(&mut self, byte: u8) -> Write<'_> {
+        Write {
+            journal: self,
+            byte,
+        }
+    }
+}
+
+pub struct Write<'a> {
+    journal: &'a mut Journal,
+    byte: u8,
+}
+impl Future for Write<'_> {
+    type Output = Result<(), Full>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let byte = self.byte;
+        let journal = &mut *self.journal;
+        if !journal.pending {
+            journal.pending = true;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        journal.pending = false;
+        if journal.bytes.len() == journal.capacity {
+            return Poll::Ready(Err(Full));
+        }
+        journal.bytes.push(byte);
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Full;
+
+#[inception(property = Persisted)]
+pub(crate) trait Persist {
+    async fn persist(&self, journal: &mut Journal) -> Result<(), Full>;
+
+    async fn nothing() -> Result<(), Full> {
+        Ok(())
+    }
+    async fn merge<H: Persist<Ret = Result<(), Full>>, R: Persist<Ret = Result<(), Full>>>(
+        l: L,
+        r: R,
+        journal: &mut Journal,
+    ) -> Result<(), Full> {
+        l.access().persist(journal).await?;
+        r.persist(journal).await
+    }
+    async fn merge_variant_field<
+        H: Persist<Ret = Result<(), Full>>,
+        R: Persist<Ret = Result<(), Full>>,
+    >(
+        l: L,
+        r: R,
+        journal: &mut Journal,
+    ) -> Result<(), Full> {
+        match l.try_access() {
+            Ok(value) => value.persist(journal).await?,
+            Err(RefEnumAccessError::Header(header)) => header.access().persist(journal).await?,
+            Err(RefEnumAccessError::EmptyField(_)) => {}
+        }
+        r.persist(journal).await
+    }
+    async fn join<F: Persist<Ret = Result<(), Full>>>(
+        fields: F,
+        journal: &mut Journal,
+    ) -> Result<(), Full> {
+        fields.persist(journal).await
+    }
+}
+
+#[primitive(property = Persisted)]
+impl Persist for u8 {
+    async fn persist(&self, journal: &mut Journal) -> Result<(), Full> {
+        journal.write(*self).await
+    }
+}
+#[primitive(property = Persisted)]
+impl Persist for u16 {
+    async fn persist(&self, journal: &mut Journal) -> Result<(), Full> {
+        for byte in self.to_le_bytes() {
+            journal.write(byte).await?;
+        }
+        Ok(())
+    }
+}
+#[primitive(property = Persisted)]
+impl Persist for VariantHeader {
+    async fn persist(&self, journal: &mut Journal) -> Result<(), Full> {
+        journal.write(self.index() as u8).await
+    }
+}
+
+#[derive(Inception)]
+#[inception(properties = [Persisted])]
+pub struct Frame {
+    pub tag: u8,
+    pub len: u16,
+}
+
+#[derive(Inception)]
+#[inception(properties = [Persisted])]
+pub enum Packet {
+    Ping,
+    Data { frame: Frame, checksum: u8 },
+}
+
+#[cfg(test)]
+mod test {
+    use std::pin::pin;
+    use std::task::Waker;
+
+    use super::*;
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn async_behavior() {
+        let packet = Packet::Data {
+            frame: Frame {
+                tag: 7,
+                len: 0x0102,
+            },
+            checksum: 9,
+        };
+        let mut journal = Journal::with_capacity(16);
+        assert_eq!(block_on(packet.persist(&mut journal)), Ok(()));
+        assert_eq!(journal.bytes, vec![1, 7, 2, 1, 9]);
+
+        let mut journal = Journal::with_capacity(16);
+        assert_eq!(block_on(Packet::Ping.persist(&mut journal)), Ok(()));
+        assert_eq!(journal.bytes, vec![0]);
+
+        let mut journal = Journal::with_capacity(3);
+        assert_eq!(block_on(packet.persist(&mut journal)), Err(Full));
+        assert_eq!(journal.bytes, vec![1, 7, 2]);
+    }
+}
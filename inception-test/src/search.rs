@@ -0,0 +1,188 @@
+use std::cell::Cell;
+use std::ops::ControlFlow;
+
+use inception::*;
+
+thread_local! {
+    static VISITED: Cell<usize> = const { Cell::new(0) };
+}
+fn visit() {
+    VISITED.with(|v| v.set(v.get() + 1));
+}
+
+#[inception(property = Searchable, short_circuit)]
+pub trait HasZero {
+    fn has_zero(&self) -> ControlFlow<()>;
+
+    fn nothing() -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+    fn merge<H: HasZero<Ret = ControlFlow<()>>, R: HasZero<Ret = ControlFlow<()>>>(
+        l: L,
+        _r: R,
+    ) -> ControlFlow<()> {
+        l.access().has_zero()
+    }
+    fn join<F: HasZero<Ret = ControlFlow<()>>>(fields: F) -> ControlFlow<()> {
+        fields.has_zero()
+    }
+}
+
+#[primitive(property = Searchable)]
+impl HasZero for u8 {
+    fn has_zero(&self) -> ControlFlow<()> {
+        visit();
+        if *self == 0 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+#[primitive(property = Searchable)]
+impl HasZero for u64 {
+    fn has_zero(&self) -> ControlFlow<()> {
+        visit();
+        if *self == 0 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+#[primitive(property = Searchable)]
+impl HasZero for VariantHeader {
+    fn has_zero(&self) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+}
+
+#[inception(property = Summable, short_circuit)]
+pub trait Total {
+    fn total(&self) -> ControlFlow<(), u64>;
+
+    fn nothing() -> ControlFlow<(), u64> {
+        ControlFlow::Continue(0)
+    }
+    fn merge<H: Total<Ret = ControlFlow<(), u64>>, R: Total<Ret = ControlFlow<(), u64>>>(
+        l: L,
+        _r: R,
+    ) -> ControlFlow<(), u64> {
+        l.access().total()
+    }
+    // Breaks on overflow.
+    fn merge_continue(head: u64, tail: u64) -> ControlFlow<(), u64> {
+        match head.checked_add(tail) {
+            Some(sum) => ControlFlow::Continue(sum),
+            None => ControlFlow::Break(()),
+        }
+    }
+    fn join<F: Total<Ret = ControlFlow<(), u64>>>(fields: F) -> ControlFlow<(), u64> {
+        fields.total()
+    }
+}
+
+#[primitive(property = Summable)]
+impl Total for u8 {
+    fn total(&self) -> ControlFlow<(), u64> {
+        visit();
+        ControlFlow::Continue(u64::from(*self))
+    }
+}
+#[primitive(property = Summable)]
+impl Total for u64 {
+    fn total(&self) -> ControlFlow<(), u64> {
+        visit();
+        ControlFlow::Continue(*self)
+    }
+}
+#[primitive(property = Summable)]
+impl Total for VariantHeader {
+    fn total(&self) -> ControlFlow<(), u64> {
+        ControlFlow::Continue(0)
+    }
+}
+
+#[derive(Inception)]
+#[inception(properties = [Searchable, Summable])]
+pub struct Dials {
+    pub first: u8,
+    pub second: u64,
+    pub third: u8,
+}
+
+#[derive(Inception)]
+#[inception(properties = [Searchable, Summable])]
+pub struct Panel {
+    pub left: Dials,
+    pub right: Dials,
+}
+
+#[derive(Inception)]
+#[inception(properties = [Searchable, Summable])]
+pub enum Reading {
+    Idle,
+    Dial(Dials),
+    Pair { a: u8, b: u8 },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn visited() -> usize {
+        VISITED.with(|v| v.replace(0))
+    }
+
+    fn dials(first: u8, second: u64, third: u8) -> Dials {
+        Dials {
+            first,
+            second,
+            third,
+        }
+    }
+
+    #[test]
+    fn control_flow() {
+        visited();
+        let panel = Panel {
+            left: dials(1, 2, 3),
+            right: dials(4, 5, 6),
+        };
+        assert_eq!(panel.has_zero(), ControlFlow::Continue(()));
+        assert_eq!(visited(), 6);
+
+        let panel = Panel {
+            left: dials(1, 0, 3),
+            right: dials(0, 5, 6),
+        };
+        assert_eq!(panel.has_zero(), ControlFlow::Break(()));
+        assert_eq!(visited(), 2);
+
+        assert_eq!(
+            Reading::Pair { a: 0, b: 1 }.has_zero(),
+            ControlFlow::Break(())
+        );
+        assert_eq!(visited(), 1);
+        assert_eq!(Reading::Idle.has_zero(), ControlFlow::Continue(()));
+        assert_eq!(visited(), 0);
+
+        let panel = Panel {
+            left: dials(1, 2, 3),
+            right: dials(4, 5, 6),
+        };
+        assert_eq!(panel.total(), ControlFlow::Continue(21));
+        assert_eq!(visited(), 6);
+        let panel = Panel {
+            left: dials(1, u64::MAX, 3),
+            right: dials(4, 5, 6),
+        };
+        assert_eq!(panel.total(), ControlFlow::Break(()));
+        assert_eq!(visited(), 3);
+        assert_eq!(
+            Reading::Pair { a: 4, b: 5 }.total(),
+            ControlFlow::Continue(9)
+        );
+        assert_eq!(Reading::Idle.total(), ControlFlow::Continue(0));
+    }
+}
@@ -0,0 +1,309 @@
+use inception::{shape::Shape, *};
+
+/// Whether every field of `self` equals the same-named field of `other`, in whichever order.
+#[inception(property = Matching, comparator(with = Rhs))]
+pub trait Matches<Rhs> {
+    fn matches(&self, other: &Rhs) -> bool;
+
+    fn nothing() -> bool {
+        true
+    }
+    fn merge<H: Matches<Ret = bool>, R: Matches<Ret = bool>>(
+        l: L,
+        r: R,
+        l2: L2,
+        other: &Rhs,
+    ) -> bool {
+        l.access().matches(l2.access()) && r.matches(other)
+    }
+    fn merge_variant_field<H: Matches<Ret = bool>, R: Matches<Ret = bool>>(
+        l: L,
+        r: R,
+        l2: L2,
+        other: &Rhs,
+    ) -> bool {
+        let head = match (l.try_access(), l2.found()) {
+            (Ok(l), Some(l2)) => l.matches(l2),
+            (Err(RefEnumAccessError::Header(_)), Some(_))
+            | (Err(RefEnumAccessError::EmptyField(_)), None) => true,
+            _ => false,
+        };
+        head && r.matches(other)
+    }
+    fn join<F: Matches<Ret = bool>>(fields: F, other: &Rhs) -> bool {
+        fields.matches(other)
+    }
+}
+
+#[primitive(property = Matching)]
+impl Matches<u8> for u8 {
+    fn matches(&self, other: &u8) -> bool {
+        self == other
+    }
+}
+#[primitive(property = Matching)]
+impl Matches<u64> for u64 {
+    fn matches(&self, other: &u64) -> bool {
+        self == other
+    }
+}
+#[primitive(property = Matching)]
+impl Matches<u128> for u128 {
+    fn matches(&self, other: &u128) -> bool {
+        self == other
+    }
+}
+#[primitive(property = Matching)]
+impl Matches<String> for String {
+    fn matches(&self, other: &String) -> bool {
+        self == other
+    }
+}
+#[primitive(property = Matching)]
+impl Matches<VariantHeader> for VariantHeader {
+    // Variants are found by name, so their indices may differ.
+    fn matches(&self, _other: &VariantHeader) -> bool {
+        true
+    }
+}
+
+/// Whether every field of `self` equals the field at the same position of `other`, whatever
+/// their names.
+#[inception(property = Positional, comparator(with = Rhs, by = index))]
+pub trait MatchesInOrder<Rhs> {
+    fn matches_in_order(&self, other: &Rhs) -> bool;
+
+    fn nothing() -> bool {
+        true
+    }
+    fn merge<H: MatchesInOrder<Ret = bool>, R: MatchesInOrder<Ret = bool>>(
+        l: L,
+        r: R,
+        l2: L2,
+        other: &Rhs,
+    ) -> bool {
+        l.access().matches_in_order(l2.access()) && r.matches_in_order(other)
+    }
+    fn merge_variant_field<H: MatchesInOrder<Ret = bool>, R: MatchesInOrder<Ret = bool>>(
+        l: L,
+        r: R,
+        l2: L2,
+        other: &Rhs,
+    ) -> bool {
+        let head = match (l.try_access(), l2.found()) {
+            (Ok(l), Some(l2)) => l.matches_in_order(l2),
+            (Err(RefEnumAccessError::Header(_)), Some(_))
+            | (Err(RefEnumAccessError::EmptyField(_)), None) => true,
+            _ => false,
+        };
+        head && r.matches_in_order(other)
+    }
+    fn join<F: MatchesInOrder<Ret = bool>>(fields: F, other: &Rhs) -> bool {
+        fields.matches_in_order(other)
+    }
+}
+
+#[primitive(property = Positional)]
+impl MatchesInOrder<u8> for u8 {
+    fn matches_in_order(&self, other: &u8) -> bool {
+        self == other
+    }
+}
+#[primitive(property = Positional)]
+impl MatchesInOrder<String> for String {
+    fn matches_in_order(&self, other: &String) -> bool {
+        self == other
+    }
+}
+
+/// A `Director` as credited, by position alone.
+#[derive(Debug, PartialEq, Inception)]
+#[inception(properties = [Positional, Shape])]
+pub struct Credit(pub String, pub u8, pub u8);
+
+/// A `Kind` by position alone.
+#[derive(Debug, PartialEq, Inception)]
+#[inception(properties = [Shape])]
+pub enum Tier {
+    Star(u64),
+    Hopeful(u8),
+}
+
+/// A `Director` as stored, with its fields in another order.
+#[derive(Debug, PartialEq, Inception)]
+#[inception(properties = [Matching, Shape])]
+pub struct DirectorRow {
+    pub age: u8,
+    pub name: String,
+    pub num_movies: u8,
+}
+
+/// A `Director` without its age.
+#[derive(Debug, PartialEq, Inception)]
+#[inception(properties = [Matching, Shape])]
+pub struct DirectorName {
+    pub name: String,
+    pub num_movies: u8,
+}
+
+/// A `Kind` as stored, with its variants in another order.
+#[derive(Debug, PartialEq, Inception)]
+#[inception(properties = [Matching, Shape])]
+pub enum KindRow {
+    Aspiring { salary: u8 },
+    BigName { salary: u64 },
+}
+
+/// The `BigName` variant of a `Kind`, alone.
+#[derive(Debug, PartialEq, Inception)]
+#[inception(properties = [Matching, Shape])]
+pub enum BigNameRow {
+    BigName { salary: u64 },
+}
+
+/// An `Actor` as stored, with its fields in another order.
+#[derive(Inception)]
+#[inception(properties = [Matching, Shape])]
+pub struct ActorRow {
+    pub kind: KindRow,
+    pub net_worth: u128,
+    pub name: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data::{Actor, Director, Kind};
+
+    #[test]
+    fn same_shape() {
+        assert_same_shape::<Director, DirectorRow, ByName>();
+        assert_same_shape::<DirectorRow, Director, ByName>();
+        assert_same_shape::<Kind, KindRow, ByName>();
+        assert_same_shape::<Director, Credit, ByIndex>();
+        assert_same_shape::<Kind, Tier, ByIndex>();
+    }
+
+    #[test]
+    fn copy_fields() {
+        let director = Director {
+            name: "Jim Jarmusch".to_string(),
+            num_movies: 14,
+            age: 72,
+        };
+        let row: DirectorRow = copy_matching_fields::<_, _, ByName>(&director).unwrap();
+        assert_eq!(
+            row,
+            DirectorRow {
+                age: 72,
+                name: "Jim Jarmusch".to_string(),
+                num_movies: 14,
+            }
+        );
+
+        let name: DirectorName = copy_matching_fields::<_, _, ByName>(&row).unwrap();
+        assert_eq!(
+            name,
+            DirectorName {
+                name: "Jim Jarmusch".to_string(),
+                num_movies: 14,
+            }
+        );
+
+        let kind: KindRow =
+            copy_matching_fields::<_, _, ByName>(&Kind::BigName { salary: 20 }).unwrap();
+        assert_eq!(kind, KindRow::BigName { salary: 20 });
+        let kind: KindRow =
+            copy_matching_fields::<_, _, ByName>(&Kind::Aspiring { salary: 2 }).unwrap();
+        assert_eq!(kind, KindRow::Aspiring { salary: 2 });
+
+        let big_name: Result<BigNameRow, _> =
+            copy_matching_fields::<_, _, ByName>(&Kind::Aspiring { salary: 2 });
+        assert!(matches!(
+            big_name,
+            Err(FromFieldsError::NoVariant { name: "BigNameRow" })
+        ));
+    }
+
+    #[test]
+    fn copy_fields_by_index() {
+        let director = Director {
+            name: "Chantal Akerman".to_string(),
+            num_movies: 45,
+            age: 65,
+        };
+        let credit: Credit = copy_matching_fields::<_, _, ByIndex>(&director).unwrap();
+        assert_eq!(credit, Credit("Chantal Akerman".to_string(), 45, 65));
+
+        let tier: Tier =
+            copy_matching_fields::<_, _, ByIndex>(&Kind::Aspiring { salary: 2 }).unwrap();
+        assert_eq!(tier, Tier::Hopeful(2));
+        let tier: Tier =
+            copy_matching_fields::<_, _, ByIndex>(&Kind::BigName { salary: 20 }).unwrap();
+        assert_eq!(tier, Tier::Star(20));
+    }
+
+    #[test]
+    fn matches() {
+        let director = Director {
+            name: "Agnès Varda".to_string(),
+            num_movies: 23,
+            age: 90,
+        };
+        let row: DirectorRow = copy_matching_fields::<_, _, ByName>(&director).unwrap();
+        assert!(director.matches(&row));
+        assert!(row.matches(&director));
+        let older = DirectorRow { age: 91, ..row };
+        assert!(!director.matches(&older));
+        assert!(!older.matches(&director));
+
+        // Only the fields of `self` are compared.
+        let name = DirectorName {
+            name: "Agnès Varda".to_string(),
+            num_movies: 23,
+        };
+        assert!(name.matches(&director));
+        assert!(name.matches(&older));
+
+        let actor = Actor {
+            name: "Isabelle Huppert".to_string(),
+            kind: Kind::BigName { salary: 20 },
+            net_worth: 100,
+        };
+        let row = ActorRow {
+            kind: KindRow::BigName { salary: 20 },
+            net_worth: 100,
+            name: "Isabelle Huppert".to_string(),
+        };
+        assert!(actor.matches(&row));
+        assert!(row.matches(&actor));
+        let paid_less = ActorRow {
+            kind: KindRow::BigName { salary: 10 },
+            ..row
+        };
+        assert!(!actor.matches(&paid_less));
+        let aspiring = ActorRow {
+            kind: KindRow::Aspiring { salary: 20 },
+            ..paid_less
+        };
+        assert!(!actor.matches(&aspiring));
+        assert!(!aspiring.matches(&actor));
+        assert!(Kind::Aspiring { salary: 20 }.matches(&KindRow::Aspiring { salary: 20 }));
+    }
+
+    #[test]
+    fn matches_in_order() {
+        let director = Director {
+            name: "Claire Denis".to_string(),
+            num_movies: 15,
+            age: 78,
+        };
+        let credit = Credit("Claire Denis".to_string(), 15, 78);
+        assert!(director.matches_in_order(&credit));
+        assert!(credit.matches_in_order(&director));
+        // Positions rather than names line fields up.
+        let swapped = Credit("Claire Denis".to_string(), 78, 15);
+        assert!(!director.matches_in_order(&swapped));
+        assert!(!swapped.matches_in_order(&director));
+    }
+}
@@ -0,0 +1,198 @@
+use inception::*;
+
+#[inception(property = Statistics)]
+pub trait Stats {
+    fn leaves(&self) -> usize;
+    fn depth(&self) -> usize;
+
+    fn leaves_nothing() -> usize {
+        0
+    }
+    fn leaves_merge<H: Stats<Ret = usize>, R: Stats<Ret = usize>>(l: L, r: R) -> usize {
+        l.access().leaves() + r.leaves()
+    }
+    fn leaves_merge_variant_field<H: Stats<Ret = usize>, R: Stats<Ret = usize>>(
+        l: L,
+        r: R,
+    ) -> usize {
+        l.try_access().map(|l| l.leaves()).unwrap_or_default() + r.leaves()
+    }
+    fn leaves_join<F: Stats<Ret = usize>>(fields: F) -> usize {
+        fields.leaves()
+    }
+
+    fn depth_nothing() -> usize {
+        0
+    }
+    fn depth_merge<H: Stats<Ret = usize>, R: Stats<Ret = usize>>(l: L, r: R) -> usize {
+        l.access().depth().max(r.depth())
+    }
+    fn depth_merge_variant_field<H: Stats<Ret = usize>, R: Stats<Ret = usize>>(
+        l: L,
+        r: R,
+    ) -> usize {
+        l.try_access()
+            .map(|l| l.depth())
+            .unwrap_or_default()
+            .max(r.depth())
+    }
+    fn depth_join<F: Stats<Ret = usize>>(fields: F) -> usize {
+        fields.depth() + 1
+    }
+}
+
+#[primitive(property = Statistics)]
+impl Stats for u8 {
+    fn leaves(&self) -> usize {
+        1
+    }
+    fn depth(&self) -> usize {
+        0
+    }
+}
+#[primitive(property = Statistics)]
+impl Stats for u64 {
+    fn leaves(&self) -> usize {
+        1
+    }
+    fn depth(&self) -> usize {
+        0
+    }
+}
+#[primitive(property = Statistics)]
+impl Stats for u128 {
+    fn leaves(&self) -> usize {
+        1
+    }
+    fn depth(&self) -> usize {
+        0
+    }
+}
+#[primitive(property = Statistics)]
+impl Stats for String {
+    fn leaves(&self) -> usize {
+        1
+    }
+    fn depth(&self) -> usize {
+        0
+    }
+}
+#[primitive(property = Statistics)]
+impl Stats for VariantHeader {
+    fn leaves(&self) -> usize {
+        0
+    }
+    fn depth(&self) -> usize {
+        0
+    }
+}
+
+/// Two behaviors with different return types and steps: `labels` leaves out
+/// `merge_variant_field`.
+#[inception(property = Tallied)]
+pub trait Tally {
+    fn total(&self) -> u64;
+    fn labels(&self) -> String;
+
+    fn total_nothing() -> u64 {
+        0
+    }
+    fn total_merge<H: Tally<Ret = u64>, R: Tally<Ret = u64>>(l: L, r: R) -> u64 {
+        l.access().total() + r.total()
+    }
+    fn total_merge_variant_field<H: Tally<Ret = u64>, R: Tally<Ret = u64>>(l: L, r: R) -> u64 {
+        l.try_access().map(|l| l.total()).unwrap_or_default() + r.total()
+    }
+    fn total_join<F: Tally<Ret = u64>>(fields: F) -> u64 {
+        fields.total()
+    }
+
+    fn labels_nothing() -> String {
+        String::new()
+    }
+    fn labels_merge<H: Tally<Ret = String>, R: Tally<Ret = String>>(l: L, r: R) -> String {
+        match r.labels() {
+            r if r.is_empty() => l.access().labels(),
+            r => format!("{} {r}", l.access().labels()),
+        }
+    }
+    fn labels_join<F: Tally<Ret = String>>(fields: F) -> String {
+        format!("({})", fields.labels())
+    }
+}
+
+#[primitive(property = Tallied)]
+impl Tally for u8 {
+    fn total(&self) -> u64 {
+        u64::from(*self)
+    }
+    fn labels(&self) -> String {
+        self.to_string()
+    }
+}
+#[primitive(property = Tallied)]
+impl Tally for u64 {
+    fn total(&self) -> u64 {
+        *self
+    }
+    fn labels(&self) -> String {
+        self.to_string()
+    }
+}
+#[primitive(property = Tallied)]
+impl Tally for VariantHeader {
+    fn total(&self) -> u64 {
+        0
+    }
+    fn labels(&self) -> String {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data::{Actor, Director, Movie};
+    use crate::default::Standard;
+
+    #[test]
+    fn several_methods() {
+        assert_eq!(Director::standard().leaves(), 3);
+        assert_eq!(Director::standard().depth(), 1);
+        assert_eq!(Actor::standard().leaves(), 3);
+        assert_eq!(Actor::standard().depth(), 2);
+        assert_eq!(Movie::standard().leaves(), 11);
+        assert_eq!(Movie::standard().depth(), 3);
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [Tallied])]
+    struct Order {
+        id: u64,
+        status: Status,
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [Tallied])]
+    enum Status {
+        Open { items: u8 },
+        Shipped(u64, u8),
+    }
+
+    #[test]
+    fn methods_with_different_steps() {
+        let order = Order {
+            id: 7,
+            status: Status::Shipped(20, 3),
+        };
+        assert_eq!(order.total(), 30);
+        assert_eq!(order.labels(), "(7 (20 3))");
+
+        let order = Order {
+            id: 1,
+            status: Status::Open { items: 2 },
+        };
+        assert_eq!(order.total(), 3);
+        assert_eq!(order.labels(), "(1 (2))");
+    }
+}
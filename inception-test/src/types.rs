@@ -231,6 +231,208 @@ where
 {
 }
 
+#[inception(property = SendTypeTree)]
+pub trait SendTypeNode {
+    #[induce(
+        base = List<()>,
+        merge = List<(Node<Head, <Head as SendTypeNode>::Children>, <Tail as SendTypeNode>::Children)> where { Head: Send + MyBound, Tail: MyBound },
+        merge_variant = List<(Node<Head, <Head as SendTypeNode>::Children>, <Tail as SendTypeNode>::Children)> where { Head: Send + MyBound, Tail: MyBound },
+        join = <Fields as SendTypeNode>::Children where { Fields: MyBound }
+    )]
+    type Children;
+
+    fn noop() -> ();
+    fn nothing() -> () {}
+    fn merge<H: SendTypeNode, R: SendTypeNode>(_l: L, _r: R) -> () {}
+    fn merge_variant_field<H: SendTypeNode, R: SendTypeNode>(_l: L, _r: R) -> () {}
+    fn join<F: SendTypeNode>(_fields: F) -> () {}
+}
+
+#[primitive(property = SendTypeTree)]
+impl SendTypeNode for u8 {
+    type Children = List<()>;
+    fn noop() {}
+}
+#[primitive(property = SendTypeTree)]
+impl SendTypeNode for String {
+    type Children = List<()>;
+    fn noop() {}
+}
+#[primitive(property = SendTypeTree)]
+impl SendTypeNode for bool {
+    type Children = List<()>;
+    fn noop() {}
+}
+#[primitive(property = SendTypeTree)]
+impl SendTypeNode for VariantHeader {
+    type Children = List<()>;
+    fn noop() {}
+}
+
+/// A zero-copy view of a value, computed by a trait with a `&self` receiver.
+#[inception(property = Viewed)]
+pub trait View {
+    #[induce(
+        base = List<()>,
+        merge = List<(<Head as View>::View<'a>, <Tail as View>::View<'a>)>,
+        merge_variant = List<(<Head as View>::View<'a>, <Tail as View>::View<'a>)>,
+        join = <Fields as View>::View<'a>
+    )]
+    type View<'a>
+    where
+        Self: 'a;
+
+    #[induce_const(
+        base = 0,
+        merge = <Head as View>::FIELDS + <Tail as View>::FIELDS,
+        merge_variant = <Head as View>::FIELDS + <Tail as View>::FIELDS,
+        join = <Fields as View>::FIELDS
+    )]
+    const FIELDS: usize;
+
+    fn fields_viewed(&self) -> usize;
+
+    fn nothing() -> usize {
+        0
+    }
+    fn merge<H: View<Ret = usize>, R: View<Ret = usize>>(l: L, r: R) -> usize {
+        l.access().fields_viewed() + r.fields_viewed()
+    }
+    fn merge_variant_field<H: View<Ret = usize>, R: View<Ret = usize>>(l: L, r: R) -> usize {
+        let viewed = match l.try_access() {
+            Ok(value) => value.fields_viewed(),
+            Err(_) => 0,
+        };
+        viewed + r.fields_viewed()
+    }
+    fn join<F: View<Ret = usize>>(fields: F) -> usize {
+        fields.fields_viewed()
+    }
+}
+
+#[primitive(property = Viewed)]
+impl View for u8 {
+    type View<'a> = &'a u8;
+    const FIELDS: usize = 1;
+    fn fields_viewed(&self) -> usize {
+        1
+    }
+}
+#[primitive(property = Viewed)]
+impl View for String {
+    type View<'a> = &'a str;
+    const FIELDS: usize = 1;
+    fn fields_viewed(&self) -> usize {
+        1
+    }
+}
+#[primitive(property = Viewed)]
+impl View for VariantHeader {
+    type View<'a> = ();
+    const FIELDS: usize = 0;
+    fn fields_viewed(&self) -> usize {
+        0
+    }
+}
+
+/// A zero-copy view of a value, returned by a behavior as its induced GAT.
+#[inception(property = Glanced)]
+pub trait Glance {
+    #[induce(
+        base = List<()>,
+        merge = List<(<Head as Glance>::Glance<'a>, <Tail as Glance>::Glance<'a>)>,
+        merge_variant = List<(Option<<Head as Glance>::Glance<'a>>, <Tail as Glance>::Glance<'a>)>,
+        join = <Fields as Glance>::Glance<'a>
+    )]
+    type Glance<'a>
+    where
+        Self: 'a;
+
+    fn glance(&self) -> Self::Glance<'_>;
+
+    fn nothing() -> Self::Glance<'_> {
+        List(())
+    }
+    fn merge<H: Glance, R: Glance>(l: L, r: R) -> Self::Glance<'_> {
+        List((l.access().glance(), r.glance()))
+    }
+    fn merge_variant_field<H: Glance, R: Glance>(l: L, r: R) -> Self::Glance<'_> {
+        List((l.try_access().ok().map(|h| h.glance()), r.glance()))
+    }
+    fn join<F: Glance>(fields: F) -> Self::Glance<'_> {
+        fields.glance()
+    }
+}
+
+#[primitive(property = Glanced)]
+impl Glance for u8 {
+    type Glance<'a> = &'a u8;
+    fn glance(&self) -> &u8 {
+        self
+    }
+}
+#[primitive(property = Glanced)]
+impl Glance for String {
+    type Glance<'a> = &'a str;
+    fn glance(&self) -> &str {
+        self
+    }
+}
+#[primitive(property = Glanced)]
+impl Glance for VariantHeader {
+    type Glance<'a> = ();
+    fn glance(&self) {}
+}
+
+/// Compile-time extents of a type, computed without any behavior methods.
+#[inception(property = Extents, types)]
+pub trait Extent {
+    #[induce_const(
+        base = 0,
+        merge = <Head as Extent>::LEAVES + <Tail as Extent>::LEAVES,
+        merge_variant = <Head as Extent>::LEAVES + <Tail as Extent>::LEAVES,
+        join = <Fields as Extent>::LEAVES
+    )]
+    const LEAVES: usize;
+
+    #[induce_const(
+        base = 0,
+        merge = deeper(<Head as Extent>::DEPTH, <Tail as Extent>::DEPTH),
+        merge_variant = deeper(<Head as Extent>::DEPTH, <Tail as Extent>::DEPTH),
+        join = <Fields as Extent>::DEPTH + 1
+    )]
+    const DEPTH: usize;
+}
+
+const fn deeper(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+#[primitive(property = Extents)]
+impl Extent for u8 {
+    const LEAVES: usize = 1;
+    const DEPTH: usize = 0;
+}
+#[primitive(property = Extents)]
+impl Extent for String {
+    const LEAVES: usize = 1;
+    const DEPTH: usize = 0;
+}
+#[primitive(property = Extents)]
+impl Extent for bool {
+    const LEAVES: usize = 1;
+    const DEPTH: usize = 0;
+}
+#[primitive(property = Extents)]
+impl Extent for VariantHeader {
+    const LEAVES: usize = 0;
+    const DEPTH: usize = 0;
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -335,4 +537,151 @@ mod test {
             )>
         );
     }
+
+    #[derive(Inception)]
+    #[inception(properties = [SendTypeTree])]
+    struct MySendTypeTree {
+        foo: String,
+        bar: bool,
+        baz: u8,
+    }
+
+    #[test]
+    fn induced_behavior_types_with_phase_bounds() {
+        assert_type_eq!(
+            <MySendTypeTree as SendTypeNode>::Children,
+            List<(
+                Node<String, List<()>>,
+                List<(Node<bool, List<()>>, List<(Node<u8, List<()>>, List<()>)>)>
+            )>
+        );
+        MySendTypeTree::noop();
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [Viewed, Glanced])]
+    struct Song {
+        title: String,
+        track: u8,
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [Viewed])]
+    struct Single {
+        side_a: Song,
+        side_b: Song,
+    }
+
+    #[test]
+    fn induced_gat_types_with_receiver() {
+        assert_type_eq!(
+            <Song as View>::View<'static>,
+            List<(&'static str, List<(&'static u8, List<()>)>)>
+        );
+        assert_type_eq!(
+            <Single as View>::View<'static>,
+            List<(
+                List<(&'static str, List<(&'static u8, List<()>)>)>,
+                List<(
+                    List<(&'static str, List<(&'static u8, List<()>)>)>,
+                    List<()>
+                )>
+            )>
+        );
+
+        let song = Song {
+            title: "Time".to_string(),
+            track: 12,
+        };
+        let view: <Song as View>::View<'_> = List((&song.title, List((&song.track, List(())))));
+        let List((title, List((track, _)))) = view;
+        assert_eq!((title, *track), ("Time", 12));
+        assert_eq!(song.fields_viewed(), 2);
+        assert_eq!(song.fields_viewed(), <Song as View>::FIELDS);
+        assert_eq!(<Single as View>::FIELDS, 4);
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [Glanced])]
+    struct Playlist {
+        name: String,
+        opener: Song,
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [Glanced])]
+    enum Release {
+        Single(Song),
+        Ep { title: String, tracks: u8 },
+    }
+
+    #[test]
+    fn induced_gat_returned_by_behavior() {
+        let song = Song {
+            title: "Time".to_string(),
+            track: 12,
+        };
+        assert_type_eq!(
+            <Song as Glance>::Glance<'static>,
+            List<(&'static str, List<(&'static u8, List<()>)>)>
+        );
+        let List((title, List((track, List(()))))) = song.glance();
+        assert_eq!((title, *track), ("Time", 12));
+
+        let playlist = Playlist {
+            name: "Sides".to_string(),
+            opener: song,
+        };
+        let List((name, List((List((title, _)), List(()))))) = playlist.glance();
+        assert_eq!((name, title), ("Sides", "Time"));
+
+        let release = Release::Ep {
+            title: "Echoes".to_string(),
+            tracks: 4,
+        };
+        let List((_, List((single, List((_, List((title, List((tracks, List(()))))))))))) =
+            release.glance();
+        assert!(single.is_none());
+        assert_eq!((title, tracks.copied()), (Some("Echoes"), Some(4)));
+        let release = Release::Single(playlist.opener);
+        let List((_, List((single, List((_, List((title, _)))))))) = release.glance();
+        let Some(List((song_title, _))) = single else {
+            panic!("the single should be viewed");
+        };
+        assert_eq!((song_title, title), ("Time", None));
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [Extents])]
+    struct Credits {
+        lead: String,
+        year: u8,
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [Extents])]
+    struct Album {
+        title: String,
+        credits: Credits,
+        live: bool,
+    }
+
+    #[derive(Inception)]
+    #[inception(properties = [Extents])]
+    enum Take {
+        Studio(Credits),
+        Live { venue: String },
+    }
+
+    const _: () = assert!(<Album as Extent>::DEPTH == 2);
+
+    #[test]
+    fn induced_consts() {
+        let slots = [0u8; <Album as Extent>::LEAVES];
+        assert_eq!(slots.len(), 4);
+        assert_eq!(<Credits as Extent>::LEAVES, 2);
+        assert_eq!(<Credits as Extent>::DEPTH, 1);
+        assert_eq!(<Take as Extent>::LEAVES, 3);
+        assert_eq!(<Take as Extent>::DEPTH, 2);
+    }
 }
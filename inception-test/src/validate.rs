@@ -0,0 +1,306 @@
+use std::cell::Cell;
+
+use inception::*;
+
+#[derive(Debug, PartialEq)]
+pub enum Invalid {
+    Empty,
+    Zero,
+}
+impl std::fmt::Display for Invalid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty"),
+            Self::Zero => write!(f, "zero"),
+        }
+    }
+}
+
+type Checked = Result<usize, FieldError<Invalid>>;
+
+thread_local! {
+    /// How many `u8`s were validated, to tell whether a traversal stopped early.
+    static CHECKED_BYTES: Cell<usize> = const { Cell::new(0) };
+}
+
+#[inception(property = Validity, fallible)]
+pub trait Validate {
+    fn validate(&self) -> Result<usize, FieldError<Invalid>>;
+
+    fn nothing() -> Checked {
+        Ok(0)
+    }
+    fn merge<H: Validate<Ret = Checked>, R: Validate<Ret = Checked>>(l: L, r: R) -> Checked {
+        Ok(l.access() + r.validate()?)
+    }
+    fn join<F: Validate<Ret = Checked>>(fields: F) -> Checked {
+        fields.validate()
+    }
+}
+
+#[primitive(property = Validity)]
+impl Validate for u8 {
+    fn validate(&self) -> Checked {
+        CHECKED_BYTES.with(|n| n.set(n.get() + 1));
+        if *self == 0 {
+            return Err(FieldError::new(Invalid::Zero));
+        }
+        Ok(1)
+    }
+}
+#[primitive(property = Validity)]
+impl Validate for String {
+    fn validate(&self) -> Checked {
+        if self.is_empty() {
+            return Err(FieldError::new(Invalid::Empty));
+        }
+        Ok(1)
+    }
+}
+#[primitive(property = Validity)]
+impl Validate for VariantHeader {
+    fn validate(&self) -> Checked {
+        Ok(0)
+    }
+}
+
+/// Collects the paths of all invalid fields, rather than stopping at the first.
+#[inception(property = Reported, field_path = path)]
+pub trait Report {
+    fn report(&self, path: &mut FieldPath, problems: &mut Vec<String>);
+
+    fn nothing() {}
+    fn merge<H: Report<Ret = ()>, R: Report<Ret = ()>>(
+        l: L,
+        r: R,
+        path: &mut FieldPath,
+        problems: &mut Vec<String>,
+    ) {
+        l.access().report(path, problems);
+        r.report(path, problems);
+    }
+    fn join<F: Report<Ret = ()>>(fields: F, path: &mut FieldPath, problems: &mut Vec<String>) {
+        fields.report(path, problems);
+    }
+}
+
+#[primitive(property = Reported)]
+impl Report for u8 {
+    fn report(&self, path: &mut FieldPath, problems: &mut Vec<String>) {
+        if *self == 0 {
+            problems.push(format!("{path}: zero"));
+        }
+    }
+}
+#[primitive(property = Reported)]
+impl Report for String {
+    fn report(&self, path: &mut FieldPath, problems: &mut Vec<String>) {
+        if self.is_empty() {
+            problems.push(format!("{path}: empty"));
+        }
+    }
+}
+#[primitive(property = Reported)]
+impl Report for VariantHeader {
+    fn report(&self, _path: &mut FieldPath, _problems: &mut Vec<String>) {}
+}
+
+/// Counts the `u8`s which are zero, leaving `merge_variant_field` to the generated default.
+#[inception(property = Zeroed)]
+pub trait CountZeros {
+    fn count_zeros(&self) -> usize;
+
+    fn nothing() -> usize {
+        0
+    }
+    fn merge<H: CountZeros<Ret = usize>, R: CountZeros<Ret = usize>>(l: L, r: R) -> usize {
+        l.access().count_zeros() + r.count_zeros()
+    }
+    fn join<F: CountZeros<Ret = usize>>(fields: F) -> usize {
+        fields.count_zeros()
+    }
+}
+
+#[primitive(property = Zeroed)]
+impl CountZeros for u8 {
+    fn count_zeros(&self) -> usize {
+        usize::from(*self == 0)
+    }
+}
+#[primitive(property = Zeroed)]
+impl CountZeros for String {
+    fn count_zeros(&self) -> usize {
+        0
+    }
+}
+#[primitive(property = Zeroed)]
+impl CountZeros for VariantHeader {
+    fn count_zeros(&self) -> usize {
+        0
+    }
+}
+
+#[derive(Inception)]
+#[inception(properties = [Validity, Reported, Zeroed])]
+pub struct Track {
+    pub title: String,
+    pub plays: u8,
+}
+
+#[derive(Inception)]
+#[inception(properties = [Validity, Reported, Zeroed])]
+pub struct Album {
+    pub name: String,
+    pub opener: Track,
+    pub closer: Track,
+}
+
+#[derive(Inception)]
+#[inception(properties = [Validity, Reported, Zeroed])]
+pub enum Release {
+    Single(Track),
+    Ep { lead: Track, bonus: u8 },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn track(title: &str, plays: u8) -> Track {
+        Track {
+            title: title.to_string(),
+            plays,
+        }
+    }
+
+    #[test]
+    fn fallible() {
+        let album = Album {
+            name: "Dreams".to_string(),
+            opener: track("Time", 3),
+            closer: track("Dream is Collapsing", 1),
+        };
+        assert_eq!(album.validate(), Ok(5));
+
+        let album = Album {
+            name: "Dreams".to_string(),
+            opener: track("", 3),
+            closer: track("Dream is Collapsing", 0),
+        };
+        let e = album.validate().unwrap_err();
+        assert_eq!(e.error, Invalid::Empty);
+        assert_eq!(
+            e.location,
+            Some(FieldLocation {
+                ty: "Track",
+                variant: None,
+                index: 0,
+                name: Some("title"),
+            })
+        );
+
+        let release = Release::Ep {
+            lead: track("Time", 3),
+            bonus: 0,
+        };
+        let e = release.validate().unwrap_err();
+        assert_eq!(e.error, Invalid::Zero);
+        assert_eq!(e.to_string(), "Release::Ep.bonus: zero");
+        assert_eq!(Release::Single(track("Time", 3)).validate(), Ok(2));
+    }
+
+    #[test]
+    fn stops_at_first_error() {
+        let album = Album {
+            name: "Dreams".to_string(),
+            opener: track("", 3),
+            closer: track("Dream is Collapsing", 0),
+        };
+        CHECKED_BYTES.with(|n| n.set(0));
+        let e = album.validate().unwrap_err();
+        assert_eq!(e.to_string(), "Track.title: empty");
+        // Neither `opener.plays` nor anything in `closer` was visited.
+        assert_eq!(CHECKED_BYTES.with(Cell::get), 0);
+
+        let release = Release::Ep {
+            lead: track("", 3),
+            bonus: 0,
+        };
+        CHECKED_BYTES.with(|n| n.set(0));
+        assert_eq!(release.validate().unwrap_err().error, Invalid::Empty);
+        assert_eq!(CHECKED_BYTES.with(Cell::get), 0);
+    }
+
+    #[test]
+    fn default_variant_field() {
+        let album = Album {
+            name: "Dreams".to_string(),
+            opener: track("Time", 0),
+            closer: track("Dream is Collapsing", 0),
+        };
+        assert_eq!(album.count_zeros(), 2);
+
+        let release = Release::Ep {
+            lead: track("Time", 0),
+            bonus: 0,
+        };
+        assert_eq!(release.count_zeros(), 2);
+        let release = Release::Ep {
+            lead: track("Time", 3),
+            bonus: 0,
+        };
+        assert_eq!(release.count_zeros(), 1);
+        assert_eq!(Release::Single(track("Time", 0)).count_zeros(), 1);
+        assert_eq!(Release::Single(track("Time", 3)).count_zeros(), 0);
+    }
+
+    #[test]
+    fn field_paths() {
+        let album = Album {
+            name: "Dreams".to_string(),
+            opener: track("", 3),
+            closer: track("Dream is Collapsing", 0),
+        };
+        let mut path = FieldPath::new();
+        let mut problems = vec![];
+        album.report(&mut path, &mut problems);
+        assert_eq!(problems, ["opener.title: empty", "closer.plays: zero"]);
+        assert_eq!(path.depth(), 0);
+
+        let mut problems = vec![];
+        Release::Ep {
+            lead: track("", 3),
+            bonus: 0,
+        }
+        .report(&mut path, &mut problems);
+        Release::Single(track("Time", 0)).report(&mut path, &mut problems);
+        assert_eq!(
+            problems,
+            [
+                "Ep.lead.title: empty",
+                "Ep.bonus: zero",
+                "Single.0.plays: zero"
+            ]
+        );
+
+        let header = FieldLocation {
+            ty: "Release",
+            variant: Some("Ep"),
+            index: field::HEADER_IDX,
+            name: None,
+        };
+        assert_eq!(header.to_string(), "Release::Ep");
+        path.push();
+        path.replace(Some(header));
+        assert_eq!(path.to_string(), "Ep");
+
+        let mut deep = FieldPath::new();
+        for _ in 0..=FieldPath::CAPACITY {
+            deep.push();
+            deep.replace(Some(header));
+        }
+        assert!(deep.is_truncated());
+        assert_eq!(deep.iter().count(), FieldPath::CAPACITY);
+        assert!(deep.to_string().ends_with("Ep.Ep.…"));
+    }
+}
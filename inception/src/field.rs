@@ -1,6 +1,10 @@
 use core::{fmt::Display, marker::PhantomData};
 
-use crate::{meta::FieldsMeta, List, VariantOffset};
+use crate::{
+    meta::{field_annotations, Annotations, FieldAnnotations, FieldsMeta},
+    ty::Variants,
+    List, VariantOffset,
+};
 
 #[macro_export]
 macro_rules! struct_field_tys {
@@ -21,15 +25,33 @@ macro_rules! enum_field_tys {
     ] => {
         $crate::list_ty![
             $(
-                $crate::VarTyField::<$crate::VariantHeader, Self, $vxs, { 0 }>,
-                $($crate::VarTyField::<$tys, Self, $vxs, $ixs>),*
+                $crate::VarTyField::<$crate::VariantHeader, Self, $vxs, { $crate::field::HEADER_IDX }>
+                $(, $crate::VarTyField::<$tys, Self, $vxs, $ixs>)*
             ),*
         ]
     }
 }
 
-pub const VARIANT_HEADER: VariantHeader = VariantHeader;
-pub struct VariantHeader;
+pub const VARIANT_HEADER: VariantHeader = VariantHeader::new(0);
+
+/// Index in the type of every variant header, kept apart from the indices of the variant's fields
+/// so that a header never reports the annotations of one of them. [`Field::IDX`] of a header is
+/// still `0`; check [`Field::IS_HEADER`] to tell it apart.
+pub const HEADER_IDX: usize = usize::MAX;
+
+/// Leading field of each enum variant, recording the index of the variant it belongs to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VariantHeader {
+    index: usize,
+}
+impl VariantHeader {
+    pub const fn new(index: usize) -> Self {
+        Self { index }
+    }
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
 
 pub trait Access {
     type Out;
@@ -173,6 +195,202 @@ impl<T, S, const VAR_IDX: usize, const IDX: usize> TryAccess for VarOwnedField<T
     }
 }
 
+/// Failure to rebuild a value from its owned fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromFieldsError {
+    /// None of the variant headers of the enum `name` held a value.
+    NoVariant { name: &'static str },
+}
+impl Display for FromFieldsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NoVariant { name } => {
+                write!(f, "Failed to determine enum variant of `{name}`.")
+            }
+        }
+    }
+}
+impl core::error::Error for FromFieldsError {}
+
+/// Position of the field at which a fallible traversal failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldLocation {
+    /// Name of the type containing the field.
+    pub ty: &'static str,
+    /// Name of the variant containing the field, for enums.
+    pub variant: Option<&'static str>,
+    /// Index of the field, or [`HEADER_IDX`] for the header of the variant.
+    pub index: usize,
+    /// Name of the field, unless it is unnamed.
+    pub name: Option<&'static str>,
+}
+impl FieldLocation {
+    /// Whether this is the header of the variant rather than one of its fields.
+    pub fn is_header(&self) -> bool {
+        self.index == HEADER_IDX
+    }
+}
+impl Display for FieldLocation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.ty)?;
+        if let Some(variant) = self.variant {
+            write!(f, "::{variant}")?;
+        }
+        match self.name {
+            _ if self.is_header() => Ok(()),
+            Some(name) => write!(f, ".{name}"),
+            None => write!(f, ".{}", self.index),
+        }
+    }
+}
+
+/// Error of a `fallible` behavior, located at the innermost field which produced it.
+///
+/// Errors raised by a `join` step, outside of any field, have no location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldError<E> {
+    pub error: E,
+    pub location: Option<FieldLocation>,
+}
+impl<E> FieldError<E> {
+    pub fn new(error: E) -> Self {
+        Self {
+            error,
+            location: None,
+        }
+    }
+}
+impl<E: Display> Display for FieldError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.location {
+            Some(location) => write!(f, "{location}: {}", self.error),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+impl<E: core::fmt::Debug + Display> core::error::Error for FieldError<E> {}
+
+/// Errors which can record the field at which they occurred.
+pub trait Locate {
+    /// Records `location`, unless a location was already recorded further down.
+    fn locate(&mut self, location: FieldLocation);
+}
+impl<E> Locate for FieldError<E> {
+    fn locate(&mut self, location: FieldLocation) {
+        if self.location.is_none() {
+            self.location = Some(location);
+        }
+    }
+}
+
+/// Locates the error of `result`, if any. Used by `fallible` behaviors after each merge step.
+pub fn locate<T, E: Locate>(
+    result: Result<T, E>,
+    location: impl FnOnce() -> FieldLocation,
+) -> Result<T, E> {
+    result.map_err(|mut e| {
+        e.locate(location());
+        e
+    })
+}
+
+/// Fields being visited by a traversal, from the outermost type inwards.
+///
+/// Behaviors naming a `&mut FieldPath` argument with `#[inception(field_path = ...)]` have it kept
+/// up to date by the generated impls: each nested type opens a level, which points at the head
+/// field while its merge step runs.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldPath {
+    fields: [Option<FieldLocation>; FieldPath::CAPACITY],
+    depth: usize,
+}
+impl FieldPath {
+    /// Levels beyond this depth are counted, but not recorded. The path is then displayed with a
+    /// trailing `…`.
+    pub const CAPACITY: usize = 32;
+
+    pub const fn new() -> Self {
+        Self {
+            fields: [None; Self::CAPACITY],
+            depth: 0,
+        }
+    }
+
+    /// Opens a level for the fields of a nested type.
+    pub fn push(&mut self) {
+        if let Some(field) = self.fields.get_mut(self.depth) {
+            *field = None;
+        }
+        self.depth += 1;
+    }
+
+    /// Closes the innermost level.
+    pub fn pop(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+
+    /// Points the innermost level at `field`, returning the field it pointed at before.
+    pub fn replace(&mut self, field: Option<FieldLocation>) -> Option<FieldLocation> {
+        match self
+            .depth
+            .checked_sub(1)
+            .and_then(|i| self.fields.get_mut(i))
+        {
+            Some(current) => core::mem::replace(current, field),
+            None => None,
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Whether levels beyond [`Self::CAPACITY`] were left out.
+    pub fn is_truncated(&self) -> bool {
+        self.depth > Self::CAPACITY
+    }
+
+    /// The field currently being visited, if it is recorded.
+    pub fn current(&self) -> Option<&FieldLocation> {
+        self.iter().last()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &FieldLocation> {
+        self.fields[..self.depth.min(Self::CAPACITY)]
+            .iter()
+            .filter_map(Option::as_ref)
+    }
+}
+impl Default for FieldPath {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Display for FieldPath {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, field) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ".")?;
+            }
+            if let Some(variant) = field.variant {
+                write!(f, "{variant}")?;
+                if field.is_header() {
+                    continue;
+                }
+                write!(f, ".")?;
+            }
+            match field.name {
+                Some(name) => write!(f, "{name}")?,
+                None => write!(f, "{}", field.index)?,
+            }
+        }
+        if self.is_truncated() {
+            write!(f, ".…")?;
+        }
+        Ok(())
+    }
+}
+
 pub trait Phantom: Sized {
     fn phantom() -> Self;
     fn copy(&self) -> Self {
@@ -184,7 +402,7 @@ impl Phantom for () {
 }
 impl Phantom for VariantHeader {
     fn phantom() -> Self {
-        Self
+        Self::default()
     }
 }
 impl<T, S, const IDX: usize> Phantom for TyField<T, S, IDX> {
@@ -241,6 +459,11 @@ impl Phantom for List<()> {
         List(())
     }
 }
+impl<T: Phantom> Phantom for Variants<T> {
+    fn phantom() -> Self {
+        Variants(T::phantom())
+    }
+}
 
 pub struct Empty<T, S, const VAR_IDX: usize, const IDX: usize>(PhantomData<T>, PhantomData<S>);
 impl<T, S, const VAR_IDX: usize, const IDX: usize> Default for Empty<T, S, VAR_IDX, IDX> {
@@ -253,8 +476,12 @@ impl<T, S, const VAR_IDX: usize, const IDX: usize> Empty<T, S, VAR_IDX, IDX> {
         Self(PhantomData, PhantomData)
     }
 }
+impl<T, S, const VAR_IDX: usize, const IDX: usize> FieldAnnotations for Empty<T, S, VAR_IDX, IDX> {
+    const ANNOTATIONS: Annotations = &[];
+}
 impl<T, S, const VAR_IDX: usize, const IDX: usize> Field for Empty<T, S, VAR_IDX, IDX> {
-    const IDX: usize = IDX;
+    const IDX: usize = var_field_idx(IDX);
+    const IS_HEADER: bool = IDX == HEADER_IDX;
     type Content = T;
     type Source = S;
     type Referenced<'a>
@@ -323,8 +550,19 @@ pub trait Kind {
     fn identifier() -> impl Display;
 }
 
-pub trait Field {
+/// Index of a variant field as reported by [`Field::IDX`], `0` for the header.
+const fn var_field_idx(idx: usize) -> usize {
+    if idx == HEADER_IDX {
+        0
+    } else {
+        idx
+    }
+}
+
+pub trait Field: FieldAnnotations {
     const IDX: usize;
+    /// Whether this is the header of an enum variant rather than one of its fields.
+    const IS_HEADER: bool = false;
     type Source;
     type Content;
     type Referenced<'a>
@@ -582,6 +820,9 @@ impl<T, S, const IDX: usize> From<T> for OwnedField<T, S, IDX> {
     }
 }
 
+impl<T, S: FieldsMeta, const IDX: usize> FieldAnnotations for TyField<T, S, IDX> {
+    const ANNOTATIONS: Annotations = field_annotations(<S as FieldsMeta>::ANNOTATIONS, 0, IDX);
+}
 impl<T, S: FieldsMeta, const IDX: usize> Field for TyField<T, S, IDX> {
     type Source = S;
     type Content = T;
@@ -596,12 +837,19 @@ impl<T, S: FieldsMeta, const IDX: usize> Field for TyField<T, S, IDX> {
         Self::Content: 'a;
     type Owned = OwnedField<T, S, IDX>;
 }
+impl<T, S: FieldsMeta, const VAR_IDX: usize, const IDX: usize> FieldAnnotations
+    for VarTyField<T, S, VAR_IDX, IDX>
+{
+    const ANNOTATIONS: Annotations =
+        field_annotations(<S as FieldsMeta>::ANNOTATIONS, VAR_IDX, IDX);
+}
 impl<T, S: FieldsMeta, const VAR_IDX: usize, const IDX: usize> Field
     for VarTyField<T, S, VAR_IDX, IDX>
 {
     type Source = S;
     type Content = T;
-    const IDX: usize = IDX;
+    const IDX: usize = var_field_idx(IDX);
+    const IS_HEADER: bool = IDX == HEADER_IDX;
     type Referenced<'a>
         = VarRefField<'a, Self::Content, S, VAR_IDX, IDX>
     where
@@ -612,12 +860,19 @@ impl<T, S: FieldsMeta, const VAR_IDX: usize, const IDX: usize> Field
         Self::Content: 'a;
     type Owned = VarOwnedField<T, S, VAR_IDX, IDX>;
 }
+impl<T, S: FieldsMeta, const VAR_IDX: usize, const IDX: usize> FieldAnnotations
+    for VarRefField<'_, T, S, VAR_IDX, IDX>
+{
+    const ANNOTATIONS: Annotations =
+        field_annotations(<S as FieldsMeta>::ANNOTATIONS, VAR_IDX, IDX);
+}
 impl<T, S: FieldsMeta, const VAR_IDX: usize, const IDX: usize> Field
     for VarRefField<'_, T, S, VAR_IDX, IDX>
 {
     type Source = S;
     type Content = T;
-    const IDX: usize = IDX;
+    const IDX: usize = var_field_idx(IDX);
+    const IS_HEADER: bool = IDX == HEADER_IDX;
     type Referenced<'a>
         = VarRefField<'a, Self::Content, S, VAR_IDX, IDX>
     where
@@ -628,12 +883,19 @@ impl<T, S: FieldsMeta, const VAR_IDX: usize, const IDX: usize> Field
         Self::Content: 'a;
     type Owned = VarOwnedField<T, S, VAR_IDX, IDX>;
 }
+impl<T, S: FieldsMeta, const VAR_IDX: usize, const IDX: usize> FieldAnnotations
+    for VarMutField<'_, T, S, VAR_IDX, IDX>
+{
+    const ANNOTATIONS: Annotations =
+        field_annotations(<S as FieldsMeta>::ANNOTATIONS, VAR_IDX, IDX);
+}
 impl<T, S: FieldsMeta, const VAR_IDX: usize, const IDX: usize> Field
     for VarMutField<'_, T, S, VAR_IDX, IDX>
 {
     type Source = S;
     type Content = T;
-    const IDX: usize = IDX;
+    const IDX: usize = var_field_idx(IDX);
+    const IS_HEADER: bool = IDX == HEADER_IDX;
     type Referenced<'a>
         = VarRefField<'a, T, S, VAR_IDX, IDX>
     where
@@ -644,12 +906,19 @@ impl<T, S: FieldsMeta, const VAR_IDX: usize, const IDX: usize> Field
         Self::Content: 'a;
     type Owned = VarOwnedField<T, S, VAR_IDX, IDX>;
 }
+impl<T, S: FieldsMeta, const VAR_IDX: usize, const IDX: usize> FieldAnnotations
+    for VarOwnedField<T, S, VAR_IDX, IDX>
+{
+    const ANNOTATIONS: Annotations =
+        field_annotations(<S as FieldsMeta>::ANNOTATIONS, VAR_IDX, IDX);
+}
 impl<T, S: FieldsMeta, const VAR_IDX: usize, const IDX: usize> Field
     for VarOwnedField<T, S, VAR_IDX, IDX>
 {
     type Source = S;
     type Content = T;
-    const IDX: usize = IDX;
+    const IDX: usize = var_field_idx(IDX);
+    const IS_HEADER: bool = IDX == HEADER_IDX;
     type Referenced<'a>
         = VarRefField<'a, T, S, VAR_IDX, IDX>
     where
@@ -661,6 +930,9 @@ impl<T, S: FieldsMeta, const VAR_IDX: usize, const IDX: usize> Field
     type Owned = VarOwnedField<T, S, VAR_IDX, IDX>;
 }
 
+impl<T, S: FieldsMeta, const IDX: usize> FieldAnnotations for RefField<'_, T, S, IDX> {
+    const ANNOTATIONS: Annotations = field_annotations(<S as FieldsMeta>::ANNOTATIONS, 0, IDX);
+}
 impl<T, S: FieldsMeta, const IDX: usize> Field for RefField<'_, T, S, IDX> {
     type Source = S;
     type Content = T;
@@ -675,6 +947,9 @@ impl<T, S: FieldsMeta, const IDX: usize> Field for RefField<'_, T, S, IDX> {
         Self::Content: 'a;
     type Owned = OwnedField<T, S, IDX>;
 }
+impl<T, S: FieldsMeta, const IDX: usize> FieldAnnotations for MutField<'_, T, S, IDX> {
+    const ANNOTATIONS: Annotations = field_annotations(<S as FieldsMeta>::ANNOTATIONS, 0, IDX);
+}
 impl<T, S: FieldsMeta, const IDX: usize> Field for MutField<'_, T, S, IDX> {
     type Source = S;
     type Content = T;
@@ -689,6 +964,9 @@ impl<T, S: FieldsMeta, const IDX: usize> Field for MutField<'_, T, S, IDX> {
         Self::Content: 'a;
     type Owned = OwnedField<T, S, IDX>;
 }
+impl<T, S: FieldsMeta, const IDX: usize> FieldAnnotations for OwnedField<T, S, IDX> {
+    const ANNOTATIONS: Annotations = field_annotations(<S as FieldsMeta>::ANNOTATIONS, 0, IDX);
+}
 impl<T, S: FieldsMeta, const IDX: usize> Field for OwnedField<T, S, IDX> {
     type Source = S;
     type Content = T;
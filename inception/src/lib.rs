@@ -1,25 +1,34 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub use field::{Field, Fields};
 pub use inception_macros::{inception, primitive, Inception};
 pub use meta::{
-    DataType, EnumMeta, EnumTy, FieldsMeta, IsPrimitive, Meta, NamedFieldsMeta, StructMeta,
-    StructTy, UnnamedFieldsMeta, VariantOffset,
+    Annotation, DataType, EnumMeta, EnumTy, FieldAnnotations, FieldsMeta, Group, IsPrimitive, Meta,
+    NamedFieldsMeta, StructMeta, StructTy, TypeName, UnnamedFieldsMeta, VariantOffset,
+    VariantWidths, WriteTypeName,
 };
 pub use ty::{
-    Compat, False, IntoTuples, List, Mask, Pad, Pad0, Pad1, Pad2, Pad3, Pad4, Pad5, Pad6, Pad7,
-    Pad8, SplitOff, SplitOffInfix, True, TruthValue, PAD_0, PAD_1, PAD_2, PAD_3, PAD_4, PAD_5,
-    PAD_6, PAD_7, PAD_8,
+    Chunk, Compat, False, IntoTuples, List, Mask, Pad, Pad0, Pad1, Pad2, Pad3, Pad4, Pad5, Pad6,
+    Pad7, Pad8, SplitOff, SplitOffInfix, True, TruthValue, Variants, PAD_0, PAD_1, PAD_2, PAD_3,
+    PAD_4, PAD_5, PAD_6, PAD_7, PAD_8,
 };
 
 pub mod field;
 pub mod meta;
+pub mod shape;
 pub mod ty;
 
 pub use field::{
-    Access, Empty, MutEnumAccessError, MutField, OwnedEnumAccessError, OwnedField, Phantom,
-    RefEnumAccessError, RefField, TryAccess, TyField, VarField, VarMutField, VarOwnedField,
-    VarRefField, VarTyField, VariantHeader,
+    Access, Empty, FieldError, FieldLocation, FieldPath, FromFieldsError, Locate,
+    MutEnumAccessError, MutField, OwnedEnumAccessError, OwnedField, Phantom, RefEnumAccessError,
+    RefField, TryAccess, TyField, VarField, VarMutField, VarOwnedField, VarRefField, VarTyField,
+    VariantHeader,
+};
+pub use shape::{
+    assert_same_shape, copy_matching_fields, ByIndex, ByName, FieldByName, Found, LineUp, NameOf,
 };
 pub use ty::Nothing;
 
@@ -47,7 +56,26 @@ pub trait Inception<X: Property, P: TruthValue = <Self as IsPrimitive<X>>::Is>:
         variant_header: &'b mut VariantHeader,
     ) -> Self::MutFields<'b>;
     fn into_fields(self) -> Self::OwnedFields;
-    fn from_fields(fields: Self::OwnedFields) -> Self;
+    fn try_from_fields(fields: Self::OwnedFields) -> Result<Self, FromFieldsError>
+    where
+        Self: Sized;
+    fn from_fields(fields: Self::OwnedFields) -> Self
+    where
+        Self: Sized,
+    {
+        match Self::try_from_fields(fields) {
+            Ok(t) => t,
+            Err(e) => panic!("{e}"),
+        }
+    }
+    /// Index of the active variant. Structs have a single variant, `0`.
+    fn variant_index(&self) -> usize {
+        0
+    }
+    /// Name of the active variant. Structs answer with their own name.
+    fn variant_name(&self) -> &'static str {
+        Self::NAME
+    }
 }
 
 pub trait Split<X: Property> {
@@ -1,11 +1,158 @@
-use core::marker::PhantomData;
+use core::{
+    fmt::{Display, Write},
+    marker::PhantomData,
+};
 
-use crate::{field::VarField, False, Field, Fields, Property, True, TruthValue};
+use crate::{
+    field::VarField,
+    ty::{Chunk, Variants},
+    False, Field, Fields, Property, True, TruthValue,
+};
 
 pub trait DataType {
     const NAME: &'static str;
+    /// Doc comment of the type, one line per `///`.
+    const DOCS: &'static str = "";
     type Ty;
+
+    /// Name of the type including its generic arguments, e.g. `Wrapper<u8>`.
+    fn type_name() -> TypeName<Self> {
+        TypeName(PhantomData)
+    }
+}
+
+/// Writes the name of a type including its generic arguments. Derived types ask each of their
+/// type arguments for its own name, so a generic argument must implement this too.
+pub trait WriteTypeName {
+    fn write_type_name(w: &mut dyn Write) -> core::fmt::Result;
+}
+
+/// Displays the name of `T` as rendered by [`WriteTypeName::write_type_name`].
+pub struct TypeName<T: ?Sized>(PhantomData<fn() -> *const T>);
+impl<T: WriteTypeName + ?Sized> Display for TypeName<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        T::write_type_name(f)
+    }
+}
+
+macro_rules! write_type_name {
+    ($($ty:ty),*) => {
+        $(
+            impl WriteTypeName for $ty {
+                fn write_type_name(w: &mut dyn Write) -> core::fmt::Result {
+                    w.write_str(stringify!($ty))
+                }
+            }
+        )*
+    };
+}
+write_type_name![
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    bool,
+    char,
+    str,
+    ()
+];
+macro_rules! write_tuple_type_name {
+    ($a:ident) => {
+        impl<$a: WriteTypeName> WriteTypeName for ($a,) {
+            fn write_type_name(w: &mut dyn Write) -> core::fmt::Result {
+                w.write_str("(")?;
+                $a::write_type_name(w)?;
+                w.write_str(",)")
+            }
+        }
+    };
+    ($a:ident, $($bs:ident),+) => {
+        impl<$a: WriteTypeName, $($bs: WriteTypeName),+> WriteTypeName for ($a, $($bs),+) {
+            fn write_type_name(w: &mut dyn Write) -> core::fmt::Result {
+                w.write_str("(")?;
+                $a::write_type_name(w)?;
+                $(
+                    w.write_str(", ")?;
+                    $bs::write_type_name(w)?;
+                )+
+                w.write_str(")")
+            }
+        }
+        write_tuple_type_name!($($bs),+);
+    };
+}
+write_tuple_type_name!(A, B, C, D, E, F, G, H, I, J);
+impl<T: WriteTypeName + ?Sized> WriteTypeName for &T {
+    fn write_type_name(w: &mut dyn Write) -> core::fmt::Result {
+        w.write_str("&")?;
+        T::write_type_name(w)
+    }
 }
+impl<T: WriteTypeName + ?Sized> WriteTypeName for &mut T {
+    fn write_type_name(w: &mut dyn Write) -> core::fmt::Result {
+        w.write_str("&mut ")?;
+        T::write_type_name(w)
+    }
+}
+impl<T: WriteTypeName> WriteTypeName for [T] {
+    fn write_type_name(w: &mut dyn Write) -> core::fmt::Result {
+        w.write_str("[")?;
+        T::write_type_name(w)?;
+        w.write_str("]")
+    }
+}
+impl<T: WriteTypeName, const N: usize> WriteTypeName for [T; N] {
+    fn write_type_name(w: &mut dyn Write) -> core::fmt::Result {
+        w.write_str("[")?;
+        T::write_type_name(w)?;
+        write!(w, "; {N}]")
+    }
+}
+impl<T: WriteTypeName> WriteTypeName for Option<T> {
+    fn write_type_name(w: &mut dyn Write) -> core::fmt::Result {
+        w.write_str("Option<")?;
+        T::write_type_name(w)?;
+        w.write_str(">")
+    }
+}
+impl<T: WriteTypeName + ?Sized> WriteTypeName for PhantomData<T> {
+    fn write_type_name(w: &mut dyn Write) -> core::fmt::Result {
+        w.write_str("PhantomData<")?;
+        T::write_type_name(w)?;
+        w.write_str(">")
+    }
+}
+#[cfg(feature = "alloc")]
+const _: () = {
+    use alloc::{boxed::Box, string::String, vec::Vec};
+
+    write_type_name![String];
+    impl<T: WriteTypeName> WriteTypeName for Vec<T> {
+        fn write_type_name(w: &mut dyn Write) -> core::fmt::Result {
+            w.write_str("Vec<")?;
+            T::write_type_name(w)?;
+            w.write_str(">")
+        }
+    }
+    impl<T: WriteTypeName + ?Sized> WriteTypeName for Box<T> {
+        fn write_type_name(w: &mut dyn Write) -> core::fmt::Result {
+            w.write_str("Box<")?;
+            T::write_type_name(w)?;
+            w.write_str(">")
+        }
+    }
+};
+
 pub struct InternalTy;
 pub struct EnumTy;
 pub struct StructTy<N>(PhantomData<N>);
@@ -21,6 +168,18 @@ where
 pub trait EnumMeta: DataType {
     const VARIANT_NAMES: &'static [&'static str];
     const FIELD_NAMES: &'static [&'static [&'static str]];
+    /// Doc comment of each variant.
+    const VARIANT_DOCS: &'static [&'static str] = &[];
+    /// Doc comment of each traversed field, per variant.
+    const FIELD_DOCS: &'static [&'static [&'static str]] = &[];
+    /// `#[inception(meta(...))]` annotations of each traversed field, per variant.
+    const FIELD_ANNOTATIONS: &'static [&'static [Annotations]] = &[];
+    /// Stringified type of each traversed field, per variant.
+    const FIELD_TYPE_NAMES: &'static [&'static [&'static str]] = &[];
+    /// Discriminant of each variant, counting explicit `= N` values and implicit ones.
+    const DISCRIMINANTS: &'static [i128] = &[];
+    /// Integer type named by `#[repr(...)]`, if any.
+    const REPR: Option<&'static str> = None;
 }
 pub trait Meta<K = <Self as DataType>::Ty>: DataType {
     fn metadata() -> Metadata;
@@ -42,11 +201,18 @@ where
             name: <T as DataType>::NAME,
             variant_names: <T as EnumMeta>::VARIANT_NAMES,
             field_names: <T as EnumMeta>::FIELD_NAMES,
+            field_type_names: <T as EnumMeta>::FIELD_TYPE_NAMES,
+            discriminants: <T as EnumMeta>::DISCRIMINANTS,
+            repr: <T as EnumMeta>::REPR,
         }
     }
 }
 pub trait StructMeta: DataType {
     const NUM_FIELDS: usize;
+    /// Stringified type of each traversed field.
+    const FIELD_TYPE_NAMES: &'static [&'static str] = &[];
+    /// `#[inception(meta(...))]` annotations of each traversed field.
+    const FIELD_ANNOTATIONS: &'static [Annotations] = &[];
     type NamedFields: TruthValue;
 }
 impl<T> Meta<StructTy<True>> for T
@@ -57,6 +223,7 @@ where
         Metadata::Struct {
             name: <T as DataType>::NAME,
             field_names: <T as NamedFieldsMeta>::FIELD_NAMES,
+            field_type_names: <T as StructMeta>::FIELD_TYPE_NAMES,
         }
     }
 }
@@ -68,43 +235,60 @@ where
         Metadata::Struct {
             name: <T as DataType>::NAME,
             field_names: &[],
+            field_type_names: <T as StructMeta>::FIELD_TYPE_NAMES,
         }
     }
 }
 
 pub trait NamedFieldsMeta<K = <Self as DataType>::Ty>: DataType {
     const FIELD_NAMES: &'static [&'static str];
+    /// Doc comment of each named field.
+    const FIELD_DOCS: &'static [&'static str] = &[];
 }
 pub trait UnnamedFieldsMeta<K = <Self as DataType>::Ty>: DataType {
     const NUM_FIELDS: usize;
+    /// Doc comment of each unnamed field.
+    const FIELD_DOCS: &'static [&'static str] = &[];
 }
 
 pub trait FieldsMeta<K = <Self as DataType>::Ty>: DataType {
     type Named: TruthValue;
+    /// Field annotations indexed by variant and then field, with a single variant for structs.
+    const ANNOTATIONS: &'static [&'static [Annotations]];
+    /// Field names indexed by variant and then field, empty for unnamed fields.
+    const NAMES: &'static [&'static [&'static str]];
 }
 impl<T> FieldsMeta<StructTy<True>> for T
 where
-    T: StructMeta + DataType<Ty = StructTy<True>>,
+    T: StructMeta + NamedFieldsMeta + DataType<Ty = StructTy<True>>,
 {
     type Named = True;
+    const NAMES: &'static [&'static [&'static str]] = &[<T as NamedFieldsMeta>::FIELD_NAMES];
+    const ANNOTATIONS: &'static [&'static [Annotations]] = &[<T as StructMeta>::FIELD_ANNOTATIONS];
 }
 impl<T> FieldsMeta<StructTy<False>> for T
 where
     T: StructMeta + DataType<Ty = StructTy<False>>,
 {
     type Named = False;
+    const ANNOTATIONS: &'static [&'static [Annotations]] = &[<T as StructMeta>::FIELD_ANNOTATIONS];
+    const NAMES: &'static [&'static [&'static str]] = &[];
 }
 impl<T> FieldsMeta<EnumTy> for T
 where
     T: EnumMeta,
 {
     type Named = False;
+    const ANNOTATIONS: &'static [&'static [Annotations]] = <T as EnumMeta>::FIELD_ANNOTATIONS;
+    const NAMES: &'static [&'static [&'static str]] = <T as EnumMeta>::FIELD_NAMES;
 }
 impl<T> FieldsMeta<InternalTy> for T
 where
     T: Fields,
 {
     type Named = True;
+    const ANNOTATIONS: &'static [&'static [Annotations]] = &[];
+    const NAMES: &'static [&'static [&'static str]] = &[];
 }
 
 #[derive(Debug)]
@@ -113,10 +297,14 @@ pub enum Metadata {
         name: &'static str,
         variant_names: &'static [&'static str],
         field_names: &'static [&'static [&'static str]],
+        field_type_names: &'static [&'static [&'static str]],
+        discriminants: &'static [i128],
+        repr: Option<&'static str>,
     },
     Struct {
         name: &'static str,
         field_names: &'static [&'static str],
+        field_type_names: &'static [&'static str],
     },
     Internal,
 }
@@ -130,11 +318,57 @@ pub trait IsPrimitive<X: Property> {
     type Is: TruthValue;
 }
 
+/// Marks each of the listed types as primitive for each of the listed properties, e.g.
+/// `primitives!(u8, u16, String => [Debug, Digest])`. The types still implement the traits of
+/// those properties by hand.
+#[macro_export]
+macro_rules! primitives {
+    (@each $ty:ty => [$($property:path),* $(,)?]) => {
+        $(
+            impl $crate::IsPrimitive<$property> for $ty {
+                type Is = $crate::True;
+            }
+        )*
+    };
+    ($($ty:ty),+ $(,)? => $properties:tt) => {
+        $($crate::primitives!(@each $ty => $properties);)+
+    };
+}
+
 pub trait VariantOffset<const N: usize> {
     type Padding;
     const PADDING: Self::Padding;
 }
 
+/// Length of each variant's run in the flattened field list seen by property `X`, header
+/// included.
+pub trait VariantWidths<X: Property> {
+    type Widths;
+    const WIDTHS: Self::Widths;
+}
+
+/// Groups the fields of `S` by variant when it is an enum, leaving those of structs as they are.
+pub trait Group<X: Property, S, Ty> {
+    type Out;
+    fn group(self) -> Self::Out;
+}
+impl<X: Property, S, N, T> Group<X, S, StructTy<N>> for T {
+    type Out = T;
+    fn group(self) -> Self::Out {
+        self
+    }
+}
+impl<X: Property, S, T> Group<X, S, EnumTy> for T
+where
+    S: VariantWidths<X>,
+    T: Chunk<<S as VariantWidths<X>>::Widths>,
+{
+    type Out = Variants<<T as Chunk<<S as VariantWidths<X>>::Widths>>::Out>;
+    fn group(self) -> Self::Out {
+        Variants(self.chunk(S::WIDTHS))
+    }
+}
+
 pub trait VariantMeta {
     const VARIANT_NAME: &'static str;
     const VARIANT_FIELD_NAMES: &'static [&'static str];
@@ -149,3 +383,51 @@ where
     const VARIANT_FIELD_NAMES: &'static [&'static str] =
         <<T as Field>::Source as EnumMeta>::FIELD_NAMES[<T as VarField>::VAR_IDX];
 }
+
+/// Value given to a key in `#[inception(meta(key = value))]`. A bare key is `Bool(true)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Annotation {
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+    Str(&'static str),
+}
+pub type Annotations = &'static [(&'static str, Annotation)];
+
+/// Static annotations of a field, available for every [`Field`]. Variant headers have none.
+pub trait FieldAnnotations {
+    const ANNOTATIONS: Annotations;
+
+    fn annotation(key: &str) -> Option<Annotation> {
+        Self::ANNOTATIONS
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| *v)
+    }
+}
+
+/// Annotations of field `idx` of variant `var_idx` in `table`, or none when out of range.
+pub const fn field_annotations(
+    table: &'static [&'static [Annotations]],
+    var_idx: usize,
+    idx: usize,
+) -> Annotations {
+    if var_idx < table.len() && idx < table[var_idx].len() {
+        table[var_idx][idx]
+    } else {
+        &[]
+    }
+}
+
+/// Name of field `idx` of variant `var_idx` in `table`, or none for unnamed fields.
+pub const fn field_name(
+    table: &'static [&'static [&'static str]],
+    var_idx: usize,
+    idx: usize,
+) -> Option<&'static str> {
+    if var_idx < table.len() && idx < table[var_idx].len() {
+        Some(table[var_idx][idx])
+    } else {
+        None
+    }
+}
@@ -0,0 +1,202 @@
+use core::marker::PhantomData;
+
+use crate::{
+    field::{OwnedField, TyField, VarOwnedField, VarTyField, HEADER_IDX},
+    meta::FieldsMeta,
+    ty::List,
+    Access, Field, Fields, FromFieldsError, Inception, Property, RefField, VarRefField,
+};
+
+/// One character of a type-level field name.
+pub struct Ch<const C: char>;
+
+/// Type-level name of a field: the name of its variant and its own, each a tuple of [`Ch`].
+///
+/// Struct fields have an empty variant name and variant headers an empty field name. Unnamed
+/// fields are named by their index, and renamed ones by their new name.
+pub struct Name<V, F>(PhantomData<(V, F)>);
+
+/// Names the field at `IDX` of the variant at `VAR_IDX`, the fields of a struct being variant 0.
+pub trait NameOf<const VAR_IDX: usize, const IDX: usize> {
+    type Name;
+}
+
+/// The field of `Self` named `N`, wherever it is declared.
+pub trait FieldByName<N> {
+    type Ty;
+    /// The field as found in the `RefFields` of `Self`, empty unless its variant is active.
+    type Ref<'a>: Found<Out = &'a Self::Ty>
+    where
+        Self: 'a,
+        Self::Ty: 'a;
+
+    fn field_by_name(&self) -> Self::Ref<'_>;
+    /// The value of the field, or of the header for an empty field name, if its variant is
+    /// active.
+    fn get_by_name(&self) -> Option<&Self::Ty>;
+}
+
+/// A field looked up by name, which holds no value if its variant is inactive.
+pub trait Found: Field + Clone + Access {
+    /// The value of the field, or of the variant header, if its variant is active.
+    fn found(self) -> Option<Self::Out>;
+}
+impl<'a, T, S: FieldsMeta, const IDX: usize> Found for RefField<'a, T, S, IDX> {
+    fn found(self) -> Option<&'a T> {
+        self.0
+    }
+}
+impl<'a, T, S, const VAR_IDX: usize, const IDX: usize> Found for VarRefField<'a, T, S, VAR_IDX, IDX>
+where
+    Self: Field,
+{
+    fn found(self) -> Option<&'a T> {
+        match self {
+            Self::Ref(field) | Self::Header(field) => field.0,
+            Self::Empty(_) => None,
+        }
+    }
+}
+
+/// Gives every derived type a field list without a property of the caller's choosing.
+pub struct Shape;
+impl Property for Shape {}
+
+/// Lines fields up by name, wherever they are declared.
+pub struct ByName;
+/// Lines fields up by position, the field at `Field::IDX` of a variant with the field at the same
+/// index of the variant at the same index.
+pub struct ByIndex;
+
+/// The field of `Other` which the mode `Self` lines up with the field at `IDX` of the variant at
+/// `VAR_IDX` of `S`.
+pub trait LineUp<S, Other, const VAR_IDX: usize, const IDX: usize> {
+    type Ty;
+    type Ref<'a>: Found<Out = &'a Self::Ty>
+    where
+        Other: 'a,
+        Self::Ty: 'a;
+
+    fn line_up(other: &Other) -> Self::Ref<'_>;
+    /// The value of the field, if its variant is active.
+    fn get(other: &Other) -> Option<&Self::Ty>;
+}
+impl<S, Other, const VAR_IDX: usize, const IDX: usize> LineUp<S, Other, VAR_IDX, IDX> for ByName
+where
+    S: NameOf<VAR_IDX, IDX>,
+    Other: FieldByName<<S as NameOf<VAR_IDX, IDX>>::Name>,
+{
+    type Ty = <Other as FieldByName<<S as NameOf<VAR_IDX, IDX>>::Name>>::Ty;
+    type Ref<'a>
+        = <Other as FieldByName<<S as NameOf<VAR_IDX, IDX>>::Name>>::Ref<'a>
+    where
+        Other: 'a,
+        Self::Ty: 'a;
+
+    fn line_up(other: &Other) -> Self::Ref<'_> {
+        other.field_by_name()
+    }
+    fn get(other: &Other) -> Option<&Self::Ty> {
+        other.get_by_name()
+    }
+}
+impl<S, Other, const VAR_IDX: usize, const IDX: usize> LineUp<S, Other, VAR_IDX, IDX> for ByIndex
+where
+    Other: NameOf<VAR_IDX, IDX> + FieldByName<<Other as NameOf<VAR_IDX, IDX>>::Name>,
+{
+    type Ty = <Other as FieldByName<<Other as NameOf<VAR_IDX, IDX>>::Name>>::Ty;
+    type Ref<'a>
+        = <Other as FieldByName<<Other as NameOf<VAR_IDX, IDX>>::Name>>::Ref<'a>
+    where
+        Other: 'a,
+        Self::Ty: 'a;
+
+    fn line_up(other: &Other) -> Self::Ref<'_> {
+        other.field_by_name()
+    }
+    fn get(other: &Other) -> Option<&Self::Ty> {
+        other.get_by_name()
+    }
+}
+
+/// Field list whose every field is lined up by `M` with one of `Other` of the same type.
+pub trait FoundIn<Other, M> {}
+impl<Other, M> FoundIn<Other, M> for List<()> {}
+impl<T, S, const IDX: usize, F, Other, M> FoundIn<Other, M> for List<(TyField<T, S, IDX>, F)>
+where
+    M: LineUp<S, Other, 0, IDX, Ty = T>,
+    F: FoundIn<Other, M>,
+{
+}
+impl<T, S, const VAR_IDX: usize, const IDX: usize, F, Other, M> FoundIn<Other, M>
+    for List<(VarTyField<T, S, VAR_IDX, IDX>, F)>
+where
+    M: LineUp<S, Other, VAR_IDX, IDX, Ty = T>,
+    F: FoundIn<Other, M>,
+{
+}
+
+/// Field list which can be cloned out of the fields of `Other` lined up by `M`.
+pub trait CopyFrom<Other, M>: FoundIn<Other, M> + Fields {
+    fn copy_from(other: &Other) -> Self::Owned;
+}
+impl<Other, M> CopyFrom<Other, M> for List<()> {
+    fn copy_from(_other: &Other) -> List<()> {
+        List(())
+    }
+}
+impl<T, S, const IDX: usize, F, Other, M> CopyFrom<Other, M> for List<(TyField<T, S, IDX>, F)>
+where
+    T: Clone,
+    TyField<T, S, IDX>: Field<Owned = OwnedField<T, S, IDX>>,
+    M: LineUp<S, Other, 0, IDX, Ty = T>,
+    F: CopyFrom<Other, M>,
+{
+    fn copy_from(other: &Other) -> Self::Owned {
+        let field = match M::get(other) {
+            Some(value) => OwnedField::new(value.clone()),
+            None => OwnedField::empty(),
+        };
+        List((field, F::copy_from(other)))
+    }
+}
+impl<T, S, const VAR_IDX: usize, const IDX: usize, F, Other, M> CopyFrom<Other, M>
+    for List<(VarTyField<T, S, VAR_IDX, IDX>, F)>
+where
+    T: Clone,
+    VarTyField<T, S, VAR_IDX, IDX>: Field<Owned = VarOwnedField<T, S, VAR_IDX, IDX>>,
+    M: LineUp<S, Other, VAR_IDX, IDX, Ty = T>,
+    F: CopyFrom<Other, M>,
+{
+    fn copy_from(other: &Other) -> Self::Owned {
+        let field = match M::get(other) {
+            Some(header) if IDX == HEADER_IDX => VarOwnedField::header(header.clone()),
+            Some(value) => VarOwnedField::new(value.clone()),
+            None => VarOwnedField::empty(),
+        };
+        List((field, F::copy_from(other)))
+    }
+}
+
+/// Fails to compile unless the fields of `A` and `B` line up by `M`, with the same types.
+pub fn assert_same_shape<A, B, M>()
+where
+    A: Inception<Shape>,
+    B: Inception<Shape>,
+    A::TyFields: FoundIn<B, M>,
+    B::TyFields: FoundIn<A, M>,
+{
+}
+
+/// Builds a `B` out of clones of the fields of an `a` lined up by `M`, which may have further
+/// fields.
+///
+/// An enum `B` takes the variant of `a` lined up by `M`, and is an error if it has no such
+/// variant.
+pub fn copy_matching_fields<A, B, M>(a: &A) -> Result<B, FromFieldsError>
+where
+    B: Inception<Shape, OwnedFields = <<B as Inception<Shape>>::TyFields as Fields>::Owned>,
+    B::TyFields: CopyFrom<A, M>,
+{
+    B::try_from_fields(<B::TyFields as CopyFrom<A, M>>::copy_from(a))
+}
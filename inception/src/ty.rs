@@ -204,6 +204,14 @@ macro_rules! pad {
         }
     };
 }
+// A single padding element, e.g. for a fieldless variant. Unlike the longer paddings below it
+// is needed whether or not `opt` is enabled.
+impl<T, U> Pad<List<((), List<()>)>> for List<(T, U)> {
+    type Out = List<((), List<(T, U)>)>;
+    fn pad(self, padding: List<((), List<()>)>) -> Self::Out {
+        List(((padding.0).0, self))
+    }
+}
 pad![([T,U], [E,E]) -> ([t,u], [a, b])];
 #[cfg(not(feature = "opt"))]
 pad![[T,U], [P, E, E, E] => [t,u], [a, b, c, d]];
@@ -343,3 +351,33 @@ where
         (self, l).split_off_infix()
     }
 }
+
+/// An enum's fields grouped by variant: a list whose entries are the run of each variant's
+/// fields, led by its header, with the remaining variants grouped again.
+pub struct Variants<T>(pub T);
+
+/// Splits a list into consecutive runs whose lengths are given by a list of paddings.
+pub trait Chunk<W> {
+    type Out;
+    fn chunk(self, widths: W) -> Self::Out;
+}
+impl<T> Chunk<List<()>> for T {
+    type Out = List<()>;
+    fn chunk(self, _widths: List<()>) -> Self::Out {
+        List(())
+    }
+}
+impl<T, W, Ws> Chunk<List<(W, Ws)>> for T
+where
+    T: SplitOff<W>,
+    <T as SplitOff<W>>::Right: Chunk<Ws>,
+{
+    type Out = List<(
+        <T as SplitOff<W>>::Left,
+        Variants<<<T as SplitOff<W>>::Right as Chunk<Ws>>::Out>,
+    )>;
+    fn chunk(self, List((w, ws)): List<(W, Ws)>) -> Self::Out {
+        let (l, r) = self.split_off(w);
+        List((l, Variants(r.chunk(ws))))
+    }
+}